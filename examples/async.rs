@@ -5,7 +5,7 @@ use tracing_subscriber::filter::EnvFilter;
 
 struct Service {
     _events: Sender<Event>,
-    _requests: Sender<ReactorReverseRequest>,
+    _requests: ReverseRequester,
 }
 
 impl Service {
@@ -17,7 +17,7 @@ impl Service {
 
 #[async_trait::async_trait]
 impl Backend for Service {
-    async fn init(events: Sender<Event>, requests: Sender<ReactorReverseRequest>) -> Self {
+    async fn init(events: Sender<Event>, requests: ReverseRequester) -> Self {
         Service {
             _events: events,
             _requests: requests,
@@ -1,6 +1,6 @@
 use serde_json::Value;
 
-use crate::models::{Breakpoint, Capabilities, Source};
+use crate::models::{Breakpoint, Capabilities, Module, Source};
 
 mod impls;
 
@@ -60,6 +60,13 @@ pub enum LoadedSourceReason {
     Removed,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModuleReason {
+    New,
+    Changed,
+    Removed,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ProcessStartMethod {
     Launch,
@@ -88,6 +95,15 @@ pub enum Event {
         reason: LoadedSourceReason,
         source: Source,
     },
+    Memory {
+        memory_reference: String,
+        offset: i64,
+        count: u64,
+    },
+    Module {
+        reason: ModuleReason,
+        module: Module,
+    },
     Output {
         category: Option<OutputCategory>,
         output: String,
@@ -122,6 +138,7 @@ pub enum Event {
         thread_id: u64,
     },
     Custom {
+        event: String,
         body: Option<Value>,
     },
 }
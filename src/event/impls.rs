@@ -181,6 +181,43 @@ impl TryFrom<String> for LoadedSourceReason {
     }
 }
 
+impl From<ModuleReason> for &'static str {
+    fn from(r: ModuleReason) -> Self {
+        match r {
+            ModuleReason::New => "new",
+            ModuleReason::Changed => "changed",
+            ModuleReason::Removed => "removed",
+        }
+    }
+}
+
+impl From<ModuleReason> for String {
+    fn from(r: ModuleReason) -> Self {
+        <&'static str>::from(r).into()
+    }
+}
+
+impl TryFrom<&str> for ModuleReason {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s {
+            "new" => Ok(ModuleReason::New),
+            "changed" => Ok(ModuleReason::Changed),
+            "removed" => Ok(ModuleReason::Removed),
+            _ => Err(Error::new("reason", Cause::ExpectsEnum)),
+        }
+    }
+}
+
+impl TryFrom<String> for ModuleReason {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.as_str().try_into()
+    }
+}
+
 impl From<ProcessStartMethod> for &'static str {
     fn from(m: ProcessStartMethod) -> Self {
         match m {
@@ -280,6 +317,33 @@ impl Event {
                 (event, Some(body))
             }
 
+            Event::Memory {
+                memory_reference,
+                offset,
+                count,
+            } => {
+                let event = "memory";
+
+                let memory_reference = utils::attribute_string("memoryReference", memory_reference);
+                let offset = utils::attribute_i64("offset", offset);
+                let count = utils::attribute_u64("count", count);
+
+                let body = utils::finalize_object(memory_reference.chain(offset).chain(count));
+
+                (event, Some(body))
+            }
+
+            Event::Module { reason, module } => {
+                let event = "module";
+
+                let reason = utils::attribute_string("reason", reason);
+                let module = utils::attribute("module", module);
+
+                let body = utils::finalize_object(reason.chain(module));
+
+                (event, Some(body))
+            }
+
             Event::Output {
                 category,
                 output,
@@ -399,10 +463,8 @@ impl Event {
                 (event, Some(body))
             }
 
-            Event::Custom { body } => {
-                let event = "custom";
-
-                (event, body)
+            Event::Custom { event, body } => {
+                return ProtocolEvent { seq, event, body };
             }
         };
 
@@ -464,12 +526,35 @@ impl TryFrom<&ProtocolEvent> for Event {
                 let map = &body.ok_or(Error::new("body", Cause::IsMandatory))?;
 
                 let reason =
-                    utils::get_str(map, "category").and_then(LoadedSourceReason::try_from)?;
+                    utils::get_str(map, "reason").and_then(LoadedSourceReason::try_from)?;
                 let source = utils::get_object(map, "source")?;
 
                 Ok(Self::LoadedSource { reason, source })
             }
 
+            "memory" => {
+                let map = &body.ok_or(Error::new("body", Cause::IsMandatory))?;
+
+                let memory_reference = utils::get_string(map, "memoryReference")?;
+                let offset = utils::get_i64(map, "offset")?;
+                let count = utils::get_u64(map, "count")?;
+
+                Ok(Self::Memory {
+                    memory_reference,
+                    offset,
+                    count,
+                })
+            }
+
+            "module" => {
+                let map = &body.ok_or(Error::new("body", Cause::IsMandatory))?;
+
+                let reason = utils::get_str(map, "reason").and_then(ModuleReason::try_from)?;
+                let module = utils::get_object(map, "module")?;
+
+                Ok(Self::Module { reason, module })
+            }
+
             "output" => {
                 let map = &body.ok_or(Error::new("body", Cause::IsMandatory))?;
 
@@ -555,11 +640,10 @@ impl TryFrom<&ProtocolEvent> for Event {
                 Ok(Self::Thread { reason, thread_id })
             }
 
-            "custom" => Ok(Self::Custom {
+            event => Ok(Self::Custom {
+                event: event.to_string(),
                 body: ev.body.as_ref().cloned(),
             }),
-
-            _ => Err(Error::new("event", Cause::ExpectsEnum)),
         }
     }
 }
@@ -510,9 +510,115 @@ fn encode_events() {
                 "body": "foo"
             }),
             decoded: Event::Custom {
+                event: "custom".into(),
                 body: Some(json!("foo")),
             },
         },
+        EventTestCase {
+            seq: 19,
+            encoded: json!({
+                "event": "rust-analyzer/exitStatus",
+                "body": {
+                    "code": 0
+                }
+            }),
+            decoded: Event::Custom {
+                event: "rust-analyzer/exitStatus".into(),
+                body: Some(json!({ "code": 0 })),
+            },
+        },
+        EventTestCase {
+            seq: 20,
+            encoded: json!({
+                "event": "vendorEvent",
+            }),
+            decoded: Event::Custom {
+                event: "vendorEvent".into(),
+                body: None,
+            },
+        },
+        EventTestCase {
+            seq: 21,
+            encoded: json!({
+                "event": "module",
+                "body": {
+                    "reason": "new",
+                    "module": {
+                        "id": 1,
+                        "name": "libfoo.so",
+                        "path": "/usr/lib/libfoo.so",
+                        "isOptimized": true,
+                        "symbolStatus": "Symbols loaded.",
+                        "addressRange": "0x7f0000000000-0x7f0000010000",
+                        "loadAddress": "0x7f0000000000",
+                    }
+                }
+            }),
+            decoded: Event::Module {
+                reason: ModuleReason::New,
+                module: Module {
+                    id: StackFrameModuleId::Number(1),
+                    name: "libfoo.so".into(),
+                    path: Some("/usr/lib/libfoo.so".into()),
+                    is_optimized: true,
+                    is_user_code: false,
+                    version: None,
+                    symbol_status: Some("Symbols loaded.".into()),
+                    symbol_file_path: None,
+                    date_time_stamp: None,
+                    address_range: Some("0x7f0000000000-0x7f0000010000".into()),
+                    additional_attributes: json!({ "loadAddress": "0x7f0000000000" })
+                        .as_object()
+                        .cloned()
+                        .unwrap_or_default(),
+                },
+            },
+        },
+        EventTestCase {
+            seq: 22,
+            encoded: json!({
+                "event": "module",
+                "body": {
+                    "reason": "removed",
+                    "module": {
+                        "id": "libbar",
+                        "name": "libbar.so",
+                    }
+                }
+            }),
+            decoded: Event::Module {
+                reason: ModuleReason::Removed,
+                module: Module {
+                    id: StackFrameModuleId::String("libbar".into()),
+                    name: "libbar.so".into(),
+                    path: None,
+                    is_optimized: false,
+                    is_user_code: false,
+                    version: None,
+                    symbol_status: None,
+                    symbol_file_path: None,
+                    date_time_stamp: None,
+                    address_range: None,
+                    additional_attributes: Default::default(),
+                },
+            },
+        },
+        EventTestCase {
+            seq: 23,
+            encoded: json!({
+                "event": "memory",
+                "body": {
+                    "memoryReference": "0x1000",
+                    "offset": -16,
+                    "count": 32,
+                }
+            }),
+            decoded: Event::Memory {
+                memory_reference: "0x1000".into(),
+                offset: -16,
+                count: 32,
+            },
+        },
     ];
 
     cases.into_iter().for_each(|case| case.run());
@@ -539,6 +645,10 @@ impl EventTestCase {
             ProtocolMessage::try_from(&encoded).expect("failed to parse encoded protocol message");
 
         let protocol = decoded.clone().into_protocol(seq);
+
+        let event = Event::try_from(&protocol).expect("failed to decode event");
+        assert_eq!(decoded, event);
+
         let protocol = ProtocolMessage::from(protocol);
 
         assert_eq!(encoded, protocol);
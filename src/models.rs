@@ -1,46 +1,86 @@
 mod attach;
 mod breakpoint_locations;
+mod cancel;
+mod completions;
 mod configuration_done;
 mod r#continue;
+mod data_breakpoints;
+mod disassemble;
 mod disconnect;
 mod evaluate;
 mod exception_info;
 mod goto;
+mod goto_targets;
 mod initialize;
 mod launch;
 mod loaded_sources;
+mod modules;
 mod next;
+mod pause;
+mod read_memory;
 mod restart;
+mod restart_frame;
 mod reverse_continue;
 mod scopes;
 mod set_breakpoints;
+mod set_exception_breakpoints;
+mod set_expression;
+mod set_function_breakpoints;
+mod set_instruction_breakpoints;
+mod set_variable;
+mod source;
 mod stack_trace;
 mod step_back;
+mod step_in;
+mod step_in_targets;
+mod step_out;
 mod terminate;
+mod terminate_threads;
 mod threads;
 mod variables;
+mod write_memory;
 
 pub use attach::*;
 pub use breakpoint_locations::*;
+pub use cancel::*;
+pub use completions::*;
 pub use configuration_done::*;
+pub use data_breakpoints::*;
+pub use disassemble::*;
 pub use disconnect::*;
 pub use evaluate::*;
 pub use exception_info::*;
 pub use goto::*;
+pub use goto_targets::*;
 pub use initialize::*;
 pub use launch::*;
 pub use loaded_sources::*;
+pub use modules::*;
 pub use next::*;
+pub use pause::*;
 pub use r#continue::*;
+pub use read_memory::*;
 pub use restart::*;
+pub use restart_frame::*;
 pub use reverse_continue::*;
 pub use scopes::*;
 pub use set_breakpoints::*;
+pub use set_exception_breakpoints::*;
+pub use set_expression::*;
+pub use set_function_breakpoints::*;
+pub use set_instruction_breakpoints::*;
+pub use set_variable::*;
+pub use source::*;
 pub use stack_trace::*;
 pub use step_back::*;
+pub use step_in::*;
+pub use step_in_targets::*;
+pub use step_out::*;
 pub use terminate::*;
+pub use terminate_threads::*;
 pub use threads::*;
 pub use variables::*;
+pub use write_memory::*;
 
 use crate::error::{Cause, Error};
 use crate::utils;
@@ -249,6 +289,29 @@ impl From<Message> for Value {
     }
 }
 
+impl From<Error> for Message {
+    fn from(error: Error) -> Self {
+        let Error { attribute, cause } = error;
+
+        let variables = [
+            ("attribute".to_string(), attribute.to_string()),
+            ("cause".to_string(), format!("{:?}", cause)),
+        ]
+        .into_iter()
+        .collect();
+
+        Self {
+            id: 0,
+            format: "{attribute}: {cause}".to_string(),
+            variables: Some(variables),
+            send_telemetry: false,
+            show_user: false,
+            url: None,
+            url_label: None,
+        }
+    }
+}
+
 impl TryFrom<&Map<String, Value>> for Message {
     type Error = Error;
 
@@ -340,7 +403,7 @@ impl From<Source> for Value {
         let (path, source_reference) = source_reference
             .map(|r| match r {
                 SourceReference::Path(path) => (Some(path), None),
-                SourceReference::Reference(n) if n == 0 => (None, None),
+                SourceReference::Reference(0) => (None, None),
                 SourceReference::Reference(n) => (None, Some(n)),
             })
             .unwrap_or((None, None));
@@ -455,7 +518,7 @@ impl TryFrom<&Map<String, Value>> for Breakpoint {
         let end_line = utils::get_u64_optional(map, "endLine")?;
         let end_column = utils::get_u64_optional(map, "endColumn")?;
         let instruction_reference = utils::get_string_optional(map, "instructionReference")?;
-        let offset = utils::get_i64_optional(map, "endColumn")?;
+        let offset = utils::get_i64_optional(map, "offset")?;
 
         Ok(Self {
             id,
@@ -941,14 +1004,21 @@ impl From<StackFrameModuleId> for Value {
 
 impl StackFrameModuleId {
     pub fn try_from_map_optional(map: &Map<String, Value>) -> Result<Option<Self>, Error> {
-        map.get("moduleId")
+        Self::try_from_attribute_optional(map, "moduleId")
+    }
+
+    pub(crate) fn try_from_attribute_optional(
+        map: &Map<String, Value>,
+        attribute: &'static str,
+    ) -> Result<Option<Self>, Error> {
+        map.get(attribute)
             .map(|id| match id {
                 Value::Number(n) => n
                     .as_u64()
-                    .ok_or_else(|| Error::new("moduleId", Cause::MustBeUnsignedInteger))
+                    .ok_or_else(|| Error::new(attribute, Cause::MustBeUnsignedInteger))
                     .map(Self::Number),
                 Value::String(s) => Ok(Self::String(s.clone())),
-                _ => Err(Error::new("moduleId", Cause::ExpectsEnum)),
+                _ => Err(Error::new(attribute, Cause::ExpectsEnum)),
             })
             .transpose()
     }
@@ -3,22 +3,32 @@ use super::*;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AttachArguments {
     pub restart: Option<Value>,
+    /// Adapter specific attributes, such as `pid` or `port`.
+    pub additional_attributes: Map<String, Value>,
 }
 
 impl From<AttachArguments> for Value {
     fn from(args: AttachArguments) -> Self {
-        let AttachArguments { restart } = args;
+        let AttachArguments {
+            restart,
+            additional_attributes,
+        } = args;
 
+        let additional_attributes = utils::attribute_additional(additional_attributes);
         let restart = utils::attribute_optional("__restart", restart);
 
-        utils::finalize_object(restart)
+        utils::finalize_object(additional_attributes.chain(restart))
     }
 }
 
 impl From<&Map<String, Value>> for AttachArguments {
     fn from(map: &Map<String, Value>) -> Self {
         let restart = utils::get_optional(map, "__restart");
+        let additional_attributes = utils::get_additional_attributes(map, &["__restart"]);
 
-        Self { restart }
+        Self {
+            restart,
+            additional_attributes,
+        }
     }
 }
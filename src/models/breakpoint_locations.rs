@@ -110,7 +110,7 @@ impl TryFrom<&Map<String, Value>> for BreakpointLocationsResponse {
     type Error = Error;
 
     fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
-        let breakpoints = utils::get_array_optional(map, "body")?;
+        let breakpoints = utils::get_array_optional(map, "breakpoints")?;
 
         Ok(Self { breakpoints })
     }
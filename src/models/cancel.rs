@@ -0,0 +1,35 @@
+use super::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CancelArguments {
+    pub request_id: Option<u64>,
+    pub progress_id: Option<String>,
+}
+
+impl From<CancelArguments> for Value {
+    fn from(args: CancelArguments) -> Self {
+        let CancelArguments {
+            request_id,
+            progress_id,
+        } = args;
+
+        let request_id = utils::attribute_u64_optional("requestId", request_id);
+        let progress_id = utils::attribute_string_optional("progressId", progress_id);
+
+        utils::finalize_object(request_id.chain(progress_id))
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for CancelArguments {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let request_id = utils::get_u64_optional(map, "requestId")?;
+        let progress_id = utils::get_string_optional(map, "progressId")?;
+
+        Ok(Self {
+            request_id,
+            progress_id,
+        })
+    }
+}
@@ -0,0 +1,261 @@
+use super::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompletionsArguments {
+    pub frame_id: Option<u64>,
+    pub text: String,
+    pub column: u64,
+    pub line: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompletionsResponse {
+    pub targets: Vec<CompletionItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompletionItem {
+    pub label: String,
+    pub text: Option<String>,
+    pub sort_text: Option<String>,
+    pub detail: Option<String>,
+    pub r#type: Option<CompletionItemType>,
+    pub start: Option<u64>,
+    pub length: Option<u64>,
+    pub selection_start: Option<u64>,
+    pub selection_length: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompletionItemType {
+    Method,
+    Function,
+    Constructor,
+    Field,
+    Variable,
+    Class,
+    Interface,
+    Module,
+    Property,
+    Unit,
+    Value,
+    Enum,
+    Keyword,
+    Snippet,
+    Text,
+    Color,
+    File,
+    Reference,
+    CustomColor,
+}
+
+impl CompletionsArguments {
+    /// Zero-based offset of `column` within `text`.
+    ///
+    /// `column_start_at_1` is the one provided by the client in [`InitializeArguments`].
+    pub fn column_offset(&self, column_start_at_1: bool) -> u64 {
+        self.column.saturating_sub(column_start_at_1 as u64)
+    }
+}
+
+impl CompletionItem {
+    /// Zero-based offset of `start` within the text of the request.
+    ///
+    /// `column_start_at_1` is the one provided by the client in [`InitializeArguments`].
+    pub fn start_offset(&self, column_start_at_1: bool) -> Option<u64> {
+        self.start
+            .map(|start| start.saturating_sub(column_start_at_1 as u64))
+    }
+
+    /// Set `start` from a zero-based offset within the text of the request.
+    ///
+    /// `column_start_at_1` is the one provided by the client in [`InitializeArguments`].
+    pub fn set_start_offset(&mut self, offset: u64, column_start_at_1: bool) {
+        self.start.replace(offset + column_start_at_1 as u64);
+    }
+}
+
+impl From<CompletionsArguments> for Value {
+    fn from(args: CompletionsArguments) -> Self {
+        let CompletionsArguments {
+            frame_id,
+            text,
+            column,
+            line,
+        } = args;
+
+        let frame_id = utils::attribute_u64_optional("frameId", frame_id);
+        let text = utils::attribute_string("text", text);
+        let column = utils::attribute_u64("column", column);
+        let line = utils::attribute_u64_optional("line", line);
+
+        utils::finalize_object(frame_id.chain(text).chain(column).chain(line))
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for CompletionsArguments {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let frame_id = utils::get_u64_optional(map, "frameId")?;
+        let text = utils::get_string(map, "text")?;
+        let column = utils::get_u64(map, "column")?;
+        let line = utils::get_u64_optional(map, "line")?;
+
+        Ok(Self {
+            frame_id,
+            text,
+            column,
+            line,
+        })
+    }
+}
+
+impl From<CompletionsResponse> for Value {
+    fn from(response: CompletionsResponse) -> Self {
+        let CompletionsResponse { targets } = response;
+
+        let targets = utils::attribute_array("targets", targets);
+
+        utils::finalize_object(targets)
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for CompletionsResponse {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let targets = utils::get_array_of_object(map, "targets")?;
+
+        Ok(Self { targets })
+    }
+}
+
+impl From<CompletionItem> for Value {
+    fn from(item: CompletionItem) -> Self {
+        let CompletionItem {
+            label,
+            text,
+            sort_text,
+            detail,
+            r#type,
+            start,
+            length,
+            selection_start,
+            selection_length,
+        } = item;
+
+        let label = utils::attribute_string("label", label);
+        let text = utils::attribute_string_optional("text", text);
+        let sort_text = utils::attribute_string_optional("sortText", sort_text);
+        let detail = utils::attribute_string_optional("detail", detail);
+        let r#type = utils::attribute_string_optional("type", r#type);
+        let start = utils::attribute_u64_optional("start", start);
+        let length = utils::attribute_u64_optional("length", length);
+        let selection_start = utils::attribute_u64_optional("selectionStart", selection_start);
+        let selection_length = utils::attribute_u64_optional("selectionLength", selection_length);
+
+        utils::finalize_object(
+            label
+                .chain(text)
+                .chain(sort_text)
+                .chain(detail)
+                .chain(r#type)
+                .chain(start)
+                .chain(length)
+                .chain(selection_start)
+                .chain(selection_length),
+        )
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for CompletionItem {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let label = utils::get_string(map, "label")?;
+        let text = utils::get_string_optional(map, "text")?;
+        let sort_text = utils::get_string_optional(map, "sortText")?;
+        let detail = utils::get_string_optional(map, "detail")?;
+        let r#type = utils::get_str_optional(map, "type")?
+            .map(CompletionItemType::try_from)
+            .transpose()?;
+        let start = utils::get_u64_optional(map, "start")?;
+        let length = utils::get_u64_optional(map, "length")?;
+        let selection_start = utils::get_u64_optional(map, "selectionStart")?;
+        let selection_length = utils::get_u64_optional(map, "selectionLength")?;
+
+        Ok(Self {
+            label,
+            text,
+            sort_text,
+            detail,
+            r#type,
+            start,
+            length,
+            selection_start,
+            selection_length,
+        })
+    }
+}
+
+impl From<CompletionItemType> for &'static str {
+    fn from(t: CompletionItemType) -> Self {
+        match t {
+            CompletionItemType::Method => "method",
+            CompletionItemType::Function => "function",
+            CompletionItemType::Constructor => "constructor",
+            CompletionItemType::Field => "field",
+            CompletionItemType::Variable => "variable",
+            CompletionItemType::Class => "class",
+            CompletionItemType::Interface => "interface",
+            CompletionItemType::Module => "module",
+            CompletionItemType::Property => "property",
+            CompletionItemType::Unit => "unit",
+            CompletionItemType::Value => "value",
+            CompletionItemType::Enum => "enum",
+            CompletionItemType::Keyword => "keyword",
+            CompletionItemType::Snippet => "snippet",
+            CompletionItemType::Text => "text",
+            CompletionItemType::Color => "color",
+            CompletionItemType::File => "file",
+            CompletionItemType::Reference => "reference",
+            CompletionItemType::CustomColor => "customcolor",
+        }
+    }
+}
+
+impl From<CompletionItemType> for String {
+    fn from(t: CompletionItemType) -> Self {
+        <&'static str>::from(t).into()
+    }
+}
+
+impl TryFrom<&str> for CompletionItemType {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s {
+            "method" => Ok(CompletionItemType::Method),
+            "function" => Ok(CompletionItemType::Function),
+            "constructor" => Ok(CompletionItemType::Constructor),
+            "field" => Ok(CompletionItemType::Field),
+            "variable" => Ok(CompletionItemType::Variable),
+            "class" => Ok(CompletionItemType::Class),
+            "interface" => Ok(CompletionItemType::Interface),
+            "module" => Ok(CompletionItemType::Module),
+            "property" => Ok(CompletionItemType::Property),
+            "unit" => Ok(CompletionItemType::Unit),
+            "value" => Ok(CompletionItemType::Value),
+            "enum" => Ok(CompletionItemType::Enum),
+            "keyword" => Ok(CompletionItemType::Keyword),
+            "snippet" => Ok(CompletionItemType::Snippet),
+            "text" => Ok(CompletionItemType::Text),
+            "color" => Ok(CompletionItemType::Color),
+            "file" => Ok(CompletionItemType::File),
+            "reference" => Ok(CompletionItemType::Reference),
+            "customcolor" => Ok(CompletionItemType::CustomColor),
+            _ => Err(Error::new("type", Cause::ExpectsEnum)),
+        }
+    }
+}
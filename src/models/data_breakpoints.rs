@@ -0,0 +1,232 @@
+use super::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataBreakpointInfoArguments {
+    pub variables_reference: Option<u64>,
+    pub name: String,
+    pub frame_id: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataBreakpointInfoResponse {
+    /// `None` if no data breakpoint can be set on the requested data.
+    pub data_id: Option<String>,
+    pub description: String,
+    pub access_types: Vec<DataBreakpointAccessType>,
+    pub can_persist: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DataBreakpointAccessType {
+    Read,
+    Write,
+    ReadWrite,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataBreakpoint {
+    pub data_id: String,
+    pub access_type: Option<DataBreakpointAccessType>,
+    pub condition: Option<String>,
+    pub hit_condition: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetDataBreakpointsArguments {
+    pub breakpoints: Vec<DataBreakpoint>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetDataBreakpointsResponse {
+    pub breakpoints: Vec<Breakpoint>,
+}
+
+impl From<DataBreakpointInfoArguments> for Value {
+    fn from(args: DataBreakpointInfoArguments) -> Self {
+        let DataBreakpointInfoArguments {
+            variables_reference,
+            name,
+            frame_id,
+        } = args;
+
+        let variables_reference =
+            utils::attribute_u64_optional("variablesReference", variables_reference);
+        let name = utils::attribute_string("name", name);
+        let frame_id = utils::attribute_u64_optional("frameId", frame_id);
+
+        utils::finalize_object(variables_reference.chain(name).chain(frame_id))
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for DataBreakpointInfoArguments {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let variables_reference = utils::get_u64_optional(map, "variablesReference")?;
+        let name = utils::get_string(map, "name")?;
+        let frame_id = utils::get_u64_optional(map, "frameId")?;
+
+        Ok(Self {
+            variables_reference,
+            name,
+            frame_id,
+        })
+    }
+}
+
+impl From<DataBreakpointInfoResponse> for Value {
+    fn from(response: DataBreakpointInfoResponse) -> Self {
+        let DataBreakpointInfoResponse {
+            data_id,
+            description,
+            access_types,
+            can_persist,
+        } = response;
+
+        let data_id = utils::attribute_string_or_null("dataId", data_id);
+        let description = utils::attribute_string("description", description);
+        let access_types =
+            utils::attribute_array_of_string_optional("accessTypes", Some(access_types));
+        let can_persist = utils::attribute_bool_optional("canPersist", can_persist);
+
+        utils::finalize_object(
+            data_id
+                .chain(description)
+                .chain(access_types)
+                .chain(can_persist),
+        )
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for DataBreakpointInfoResponse {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let data_id = utils::get_string_or_null(map, "dataId")?;
+        let description = utils::get_string(map, "description")?;
+        let access_types = utils::get_array_of_string_optional(map, "accessTypes")?
+            .iter()
+            .map(|s| DataBreakpointAccessType::try_from(s.as_str()))
+            .collect::<Result<_, _>>()?;
+        let can_persist = utils::get_bool_optional(map, "canPersist")?;
+
+        Ok(Self {
+            data_id,
+            description,
+            access_types,
+            can_persist,
+        })
+    }
+}
+
+impl From<DataBreakpointAccessType> for &'static str {
+    fn from(t: DataBreakpointAccessType) -> Self {
+        match t {
+            DataBreakpointAccessType::Read => "read",
+            DataBreakpointAccessType::Write => "write",
+            DataBreakpointAccessType::ReadWrite => "readWrite",
+        }
+    }
+}
+
+impl From<DataBreakpointAccessType> for String {
+    fn from(t: DataBreakpointAccessType) -> Self {
+        <&'static str>::from(t).into()
+    }
+}
+
+impl TryFrom<&str> for DataBreakpointAccessType {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s {
+            "read" => Ok(DataBreakpointAccessType::Read),
+            "write" => Ok(DataBreakpointAccessType::Write),
+            "readWrite" => Ok(DataBreakpointAccessType::ReadWrite),
+            _ => Err(Error::new("accessType", Cause::ExpectsEnum)),
+        }
+    }
+}
+
+impl From<DataBreakpoint> for Value {
+    fn from(breakpoint: DataBreakpoint) -> Self {
+        let DataBreakpoint {
+            data_id,
+            access_type,
+            condition,
+            hit_condition,
+        } = breakpoint;
+
+        let data_id = utils::attribute_string("dataId", data_id);
+        let access_type = utils::attribute_string_optional("accessType", access_type);
+        let condition = utils::attribute_string_optional("condition", condition);
+        let hit_condition = utils::attribute_string_optional("hitCondition", hit_condition);
+
+        utils::finalize_object(
+            data_id
+                .chain(access_type)
+                .chain(condition)
+                .chain(hit_condition),
+        )
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for DataBreakpoint {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let data_id = utils::get_string(map, "dataId")?;
+        let access_type = utils::get_str_optional(map, "accessType")?
+            .map(DataBreakpointAccessType::try_from)
+            .transpose()?;
+        let condition = utils::get_string_optional(map, "condition")?;
+        let hit_condition = utils::get_string_optional(map, "hitCondition")?;
+
+        Ok(Self {
+            data_id,
+            access_type,
+            condition,
+            hit_condition,
+        })
+    }
+}
+
+impl From<SetDataBreakpointsArguments> for Value {
+    fn from(args: SetDataBreakpointsArguments) -> Self {
+        let SetDataBreakpointsArguments { breakpoints } = args;
+
+        let breakpoints = utils::attribute_array("breakpoints", breakpoints);
+
+        utils::finalize_object(breakpoints)
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for SetDataBreakpointsArguments {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let breakpoints = utils::get_array_of_object(map, "breakpoints")?;
+
+        Ok(Self { breakpoints })
+    }
+}
+
+impl From<SetDataBreakpointsResponse> for Value {
+    fn from(response: SetDataBreakpointsResponse) -> Self {
+        let SetDataBreakpointsResponse { breakpoints } = response;
+
+        let breakpoints = utils::attribute_array("breakpoints", breakpoints);
+
+        utils::finalize_object(breakpoints)
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for SetDataBreakpointsResponse {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let breakpoints = utils::get_array_of_object(map, "breakpoints")?;
+
+        Ok(Self { breakpoints })
+    }
+}
@@ -0,0 +1,162 @@
+use super::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisassembleArguments {
+    pub memory_reference: String,
+    pub offset: Option<i64>,
+    pub instruction_offset: Option<i64>,
+    pub instruction_count: u64,
+    pub resolve_symbols: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisassembleResponse {
+    pub instructions: Vec<DisassembledInstruction>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisassembledInstruction {
+    pub address: String,
+    pub instruction_bytes: Option<String>,
+    pub instruction: String,
+    pub symbol: Option<String>,
+    pub location: Option<Source>,
+    pub line: Option<u64>,
+    pub column: Option<u64>,
+    pub end_line: Option<u64>,
+    pub end_column: Option<u64>,
+}
+
+impl From<DisassembleArguments> for Value {
+    fn from(args: DisassembleArguments) -> Self {
+        let DisassembleArguments {
+            memory_reference,
+            offset,
+            instruction_offset,
+            instruction_count,
+            resolve_symbols,
+        } = args;
+
+        let memory_reference = utils::attribute_string("memoryReference", memory_reference);
+        let offset = utils::attribute_i64_optional("offset", offset);
+        let instruction_offset =
+            utils::attribute_i64_optional("instructionOffset", instruction_offset);
+        let instruction_count = utils::attribute_u64("instructionCount", instruction_count);
+        let resolve_symbols = utils::attribute_bool_optional("resolveSymbols", resolve_symbols);
+
+        utils::finalize_object(
+            memory_reference
+                .chain(offset)
+                .chain(instruction_offset)
+                .chain(instruction_count)
+                .chain(resolve_symbols),
+        )
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for DisassembleArguments {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let memory_reference = utils::get_string(map, "memoryReference")?;
+        let offset = utils::get_i64_optional(map, "offset")?;
+        let instruction_offset = utils::get_i64_optional(map, "instructionOffset")?;
+        let instruction_count = utils::get_u64(map, "instructionCount")?;
+        let resolve_symbols = utils::get_bool_optional(map, "resolveSymbols")?;
+
+        Ok(Self {
+            memory_reference,
+            offset,
+            instruction_offset,
+            instruction_count,
+            resolve_symbols,
+        })
+    }
+}
+
+impl From<DisassembleResponse> for Value {
+    fn from(response: DisassembleResponse) -> Self {
+        let DisassembleResponse { instructions } = response;
+
+        let instructions = utils::attribute_array("instructions", instructions);
+
+        utils::finalize_object(instructions)
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for DisassembleResponse {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let instructions = utils::get_array_of_object(map, "instructions")?;
+
+        Ok(Self { instructions })
+    }
+}
+
+impl From<DisassembledInstruction> for Value {
+    fn from(instruction: DisassembledInstruction) -> Self {
+        let DisassembledInstruction {
+            address,
+            instruction_bytes,
+            instruction,
+            symbol,
+            location,
+            line,
+            column,
+            end_line,
+            end_column,
+        } = instruction;
+
+        let address = utils::attribute_string("address", address);
+        let instruction_bytes =
+            utils::attribute_string_optional("instructionBytes", instruction_bytes);
+        let instruction = utils::attribute_string("instruction", instruction);
+        let symbol = utils::attribute_string_optional("symbol", symbol);
+        let location = utils::attribute_optional("location", location);
+        let line = utils::attribute_u64_optional("line", line);
+        let column = utils::attribute_u64_optional("column", column);
+        let end_line = utils::attribute_u64_optional("endLine", end_line);
+        let end_column = utils::attribute_u64_optional("endColumn", end_column);
+
+        utils::finalize_object(
+            address
+                .chain(instruction_bytes)
+                .chain(instruction)
+                .chain(symbol)
+                .chain(location)
+                .chain(line)
+                .chain(column)
+                .chain(end_line)
+                .chain(end_column),
+        )
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for DisassembledInstruction {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let address = utils::get_string(map, "address")?;
+        let instruction_bytes = utils::get_string_optional(map, "instructionBytes")?;
+        let instruction = utils::get_string(map, "instruction")?;
+        let symbol = utils::get_string_optional(map, "symbol")?;
+        let location = utils::get_object_optional(map, "location")?;
+        let line = utils::get_u64_optional(map, "line")?;
+        let column = utils::get_u64_optional(map, "column")?;
+        let end_line = utils::get_u64_optional(map, "endLine")?;
+        let end_column = utils::get_u64_optional(map, "endColumn")?;
+
+        Ok(Self {
+            address,
+            instruction_bytes,
+            instruction,
+            symbol,
+            location,
+            line,
+            column,
+            end_line,
+            end_column,
+        })
+    }
+}
@@ -0,0 +1,135 @@
+use super::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GotoTargetsArguments {
+    pub source: Source,
+    pub line: u64,
+    pub column: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GotoTargetsResponse {
+    pub targets: Vec<GotoTarget>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GotoTarget {
+    pub id: u64,
+    pub label: String,
+    pub line: u64,
+    pub column: Option<u64>,
+    pub end_line: Option<u64>,
+    pub end_column: Option<u64>,
+    pub instruction_pointer_reference: Option<String>,
+}
+
+impl From<GotoTargetsArguments> for Value {
+    fn from(args: GotoTargetsArguments) -> Self {
+        let GotoTargetsArguments {
+            source,
+            line,
+            column,
+        } = args;
+
+        let source = utils::attribute("source", source);
+        let line = utils::attribute_u64("line", line);
+        let column = utils::attribute_u64_optional("column", column);
+
+        utils::finalize_object(source.chain(line).chain(column))
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for GotoTargetsArguments {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let source = utils::get_object(map, "source")?;
+        let line = utils::get_u64(map, "line")?;
+        let column = utils::get_u64_optional(map, "column")?;
+
+        Ok(Self {
+            source,
+            line,
+            column,
+        })
+    }
+}
+
+impl From<GotoTargetsResponse> for Value {
+    fn from(response: GotoTargetsResponse) -> Self {
+        let GotoTargetsResponse { targets } = response;
+
+        let targets = utils::attribute_array("targets", targets);
+
+        utils::finalize_object(targets)
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for GotoTargetsResponse {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let targets = utils::get_array_of_object(map, "targets")?;
+
+        Ok(Self { targets })
+    }
+}
+
+impl From<GotoTarget> for Value {
+    fn from(target: GotoTarget) -> Self {
+        let GotoTarget {
+            id,
+            label,
+            line,
+            column,
+            end_line,
+            end_column,
+            instruction_pointer_reference,
+        } = target;
+
+        let id = utils::attribute_u64("id", id);
+        let label = utils::attribute_string("label", label);
+        let line = utils::attribute_u64("line", line);
+        let column = utils::attribute_u64_optional("column", column);
+        let end_line = utils::attribute_u64_optional("endLine", end_line);
+        let end_column = utils::attribute_u64_optional("endColumn", end_column);
+        let instruction_pointer_reference = utils::attribute_string_optional(
+            "instructionPointerReference",
+            instruction_pointer_reference,
+        );
+
+        utils::finalize_object(
+            id.chain(label)
+                .chain(line)
+                .chain(column)
+                .chain(end_line)
+                .chain(end_column)
+                .chain(instruction_pointer_reference),
+        )
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for GotoTarget {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let id = utils::get_u64(map, "id")?;
+        let label = utils::get_string(map, "label")?;
+        let line = utils::get_u64(map, "line")?;
+        let column = utils::get_u64_optional(map, "column")?;
+        let end_line = utils::get_u64_optional(map, "endLine")?;
+        let end_column = utils::get_u64_optional(map, "endColumn")?;
+        let instruction_pointer_reference =
+            utils::get_string_optional(map, "instructionPointerReference")?;
+
+        Ok(Self {
+            id,
+            label,
+            line,
+            column,
+            end_line,
+            end_column,
+            instruction_pointer_reference,
+        })
+    }
+}
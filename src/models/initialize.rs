@@ -11,7 +11,9 @@ pub struct InitializeArguments {
     pub client_name: Option<String>,
     pub adapter_id: String,
     pub locale: Option<String>,
+    /// Defaults to `true` if omitted by the client.
     pub lines_start_at_1: bool,
+    /// Defaults to `true` if omitted by the client.
     pub column_start_at_1: bool,
     pub path_format: Option<PathFormat>,
     pub supports_variable_type: bool,
@@ -22,6 +24,7 @@ pub struct InitializeArguments {
     pub supports_invalidated_event: bool,
     pub supports_memory_event: bool,
     pub supports_args_can_be_interpreted_by_shell: bool,
+    pub supports_start_debugging_request: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -57,14 +60,15 @@ impl From<InitializeArguments> for Value {
             supports_invalidated_event,
             supports_memory_event,
             supports_args_can_be_interpreted_by_shell,
+            supports_start_debugging_request,
         } = args;
 
         let client_id = utils::attribute_string_optional("clientId", client_id);
         let client_name = utils::attribute_string_optional("clientName", client_name);
         let adapter_id = utils::attribute_string("adapterID", adapter_id);
         let locale = utils::attribute_string_optional("locale", locale);
-        let lines_start_at_1 = utils::attribute_bool_optional("linesStartAt1", lines_start_at_1);
-        let column_start_at_1 = utils::attribute_bool_optional("columnStartAt1", column_start_at_1);
+        let lines_start_at_1 = utils::attribute_bool("linesStartAt1", lines_start_at_1);
+        let column_start_at_1 = utils::attribute_bool("columnsStartAt1", column_start_at_1);
         let path_format = utils::attribute_string_optional("pathFormat", path_format);
         let supports_variable_type =
             utils::attribute_bool_optional("supportsVariableType", supports_variable_type);
@@ -88,6 +92,10 @@ impl From<InitializeArguments> for Value {
             "supportsArgsCanBeInterpretedByShell",
             supports_args_can_be_interpreted_by_shell,
         );
+        let supports_start_debugging_request = utils::attribute_bool_optional(
+            "supportsStartDebuggingRequest",
+            supports_start_debugging_request,
+        );
 
         utils::finalize_object(
             client_id
@@ -104,7 +112,8 @@ impl From<InitializeArguments> for Value {
                 .chain(support_progress_reporting)
                 .chain(supports_invalidated_event)
                 .chain(supports_memory_event)
-                .chain(supports_args_can_be_interpreted_by_shell),
+                .chain(supports_args_can_be_interpreted_by_shell)
+                .chain(supports_start_debugging_request),
         )
     }
 }
@@ -117,8 +126,8 @@ impl TryFrom<&Map<String, Value>> for InitializeArguments {
         let client_name = utils::get_string_optional(map, "clientName")?;
         let adapter_id = utils::get_string(map, "adapterID")?;
         let locale = utils::get_string_optional(map, "locale")?;
-        let lines_start_at_1 = utils::get_bool_optional(map, "linesStartAt1")?;
-        let column_start_at_1 = utils::get_bool_optional(map, "columnStartAt1")?;
+        let lines_start_at_1 = utils::get_bool_or_true(map, "linesStartAt1")?;
+        let column_start_at_1 = utils::get_bool_or_true(map, "columnsStartAt1")?;
         let path_format = utils::get_string_optional(map, "pathFormat")?.map(PathFormat::from);
         let supports_variable_type = utils::get_bool_optional(map, "supportsVariableType")?;
         let supports_variable_paging = utils::get_bool_optional(map, "supportsVariablePaging")?;
@@ -131,6 +140,8 @@ impl TryFrom<&Map<String, Value>> for InitializeArguments {
         let supports_memory_event = utils::get_bool_optional(map, "supportsMemoryEvent")?;
         let supports_args_can_be_interpreted_by_shell =
             utils::get_bool_optional(map, "supportsArgsCanBeInterpretedByShell")?;
+        let supports_start_debugging_request =
+            utils::get_bool_optional(map, "supportsStartDebuggingRequest")?;
 
         Ok(Self {
             client_id,
@@ -148,6 +159,7 @@ impl TryFrom<&Map<String, Value>> for InitializeArguments {
             supports_invalidated_event,
             supports_memory_event,
             supports_args_can_be_interpreted_by_shell,
+            supports_start_debugging_request,
         })
     }
 }
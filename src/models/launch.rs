@@ -4,16 +4,23 @@ use super::*;
 pub struct LaunchArguments {
     pub no_debug: bool,
     pub restart: Option<Value>,
+    /// Adapter specific attributes, such as `program`, `args` or `cwd`.
+    pub additional_attributes: Map<String, Value>,
 }
 
 impl From<LaunchArguments> for Value {
     fn from(args: LaunchArguments) -> Self {
-        let LaunchArguments { no_debug, restart } = args;
+        let LaunchArguments {
+            no_debug,
+            restart,
+            additional_attributes,
+        } = args;
 
+        let additional_attributes = utils::attribute_additional(additional_attributes);
         let no_debug = utils::attribute_bool_optional("noDebug", no_debug);
         let restart = utils::attribute_optional("__restart", restart);
 
-        utils::finalize_object(no_debug.chain(restart))
+        utils::finalize_object(additional_attributes.chain(no_debug).chain(restart))
     }
 }
 
@@ -23,7 +30,13 @@ impl TryFrom<&Map<String, Value>> for LaunchArguments {
     fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
         let no_debug = utils::get_bool_optional(map, "noDebug")?;
         let restart = utils::get_optional(map, "__restart");
+        let additional_attributes =
+            utils::get_additional_attributes(map, &["noDebug", "__restart"]);
 
-        Ok(Self { no_debug, restart })
+        Ok(Self {
+            no_debug,
+            restart,
+            additional_attributes,
+        })
     }
 }
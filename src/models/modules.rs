@@ -0,0 +1,176 @@
+use super::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModulesArguments {
+    pub start_module: Option<u64>,
+    pub module_count: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModulesResponse {
+    pub modules: Vec<Module>,
+    pub total_modules: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Module {
+    pub id: StackFrameModuleId,
+    pub name: String,
+    pub path: Option<String>,
+    pub is_optimized: bool,
+    pub is_user_code: bool,
+    pub version: Option<String>,
+    pub symbol_status: Option<String>,
+    pub symbol_file_path: Option<String>,
+    pub date_time_stamp: Option<String>,
+    pub address_range: Option<String>,
+    /// Attributes described by `Capabilities::additional_module_columns`.
+    pub additional_attributes: Map<String, Value>,
+}
+
+impl From<ModulesArguments> for Value {
+    fn from(args: ModulesArguments) -> Self {
+        let ModulesArguments {
+            start_module,
+            module_count,
+        } = args;
+
+        let start_module = utils::attribute_u64_optional("startModule", start_module);
+        let module_count = utils::attribute_u64_optional("moduleCount", module_count);
+
+        utils::finalize_object(start_module.chain(module_count))
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for ModulesArguments {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let start_module = utils::get_u64_optional(map, "startModule")?;
+        let module_count = utils::get_u64_optional(map, "moduleCount")?;
+
+        Ok(Self {
+            start_module,
+            module_count,
+        })
+    }
+}
+
+impl From<ModulesResponse> for Value {
+    fn from(response: ModulesResponse) -> Self {
+        let ModulesResponse {
+            modules,
+            total_modules,
+        } = response;
+
+        let modules = utils::attribute_array("modules", modules);
+        let total_modules = utils::attribute_u64_optional("totalModules", total_modules);
+
+        utils::finalize_object(modules.chain(total_modules))
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for ModulesResponse {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let modules = utils::get_array_of_object(map, "modules")?;
+        let total_modules = utils::get_u64_optional(map, "totalModules")?;
+
+        Ok(Self {
+            modules,
+            total_modules,
+        })
+    }
+}
+
+impl From<Module> for Value {
+    fn from(module: Module) -> Self {
+        let Module {
+            id,
+            name,
+            path,
+            is_optimized,
+            is_user_code,
+            version,
+            symbol_status,
+            symbol_file_path,
+            date_time_stamp,
+            address_range,
+            additional_attributes,
+        } = module;
+
+        let additional_attributes = utils::attribute_additional(additional_attributes);
+        let id = utils::attribute("id", id);
+        let name = utils::attribute_string("name", name);
+        let path = utils::attribute_string_optional("path", path);
+        let is_optimized = utils::attribute_bool_optional("isOptimized", is_optimized);
+        let is_user_code = utils::attribute_bool_optional("isUserCode", is_user_code);
+        let version = utils::attribute_string_optional("version", version);
+        let symbol_status = utils::attribute_string_optional("symbolStatus", symbol_status);
+        let symbol_file_path = utils::attribute_string_optional("symbolFilePath", symbol_file_path);
+        let date_time_stamp = utils::attribute_string_optional("dateTimeStamp", date_time_stamp);
+        let address_range = utils::attribute_string_optional("addressRange", address_range);
+
+        utils::finalize_object(
+            additional_attributes
+                .chain(id)
+                .chain(name)
+                .chain(path)
+                .chain(is_optimized)
+                .chain(is_user_code)
+                .chain(version)
+                .chain(symbol_status)
+                .chain(symbol_file_path)
+                .chain(date_time_stamp)
+                .chain(address_range),
+        )
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for Module {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let id = StackFrameModuleId::try_from_attribute_optional(map, "id")?
+            .ok_or_else(|| Error::new("id", Cause::IsMandatory))?;
+        let name = utils::get_string(map, "name")?;
+        let path = utils::get_string_optional(map, "path")?;
+        let is_optimized = utils::get_bool_optional(map, "isOptimized")?;
+        let is_user_code = utils::get_bool_optional(map, "isUserCode")?;
+        let version = utils::get_string_optional(map, "version")?;
+        let symbol_status = utils::get_string_optional(map, "symbolStatus")?;
+        let symbol_file_path = utils::get_string_optional(map, "symbolFilePath")?;
+        let date_time_stamp = utils::get_string_optional(map, "dateTimeStamp")?;
+        let address_range = utils::get_string_optional(map, "addressRange")?;
+        let additional_attributes = utils::get_additional_attributes(
+            map,
+            &[
+                "id",
+                "name",
+                "path",
+                "isOptimized",
+                "isUserCode",
+                "version",
+                "symbolStatus",
+                "symbolFilePath",
+                "dateTimeStamp",
+                "addressRange",
+            ],
+        );
+
+        Ok(Self {
+            id,
+            name,
+            path,
+            is_optimized,
+            is_user_code,
+            version,
+            symbol_status,
+            symbol_file_path,
+            date_time_stamp,
+            address_range,
+            additional_attributes,
+        })
+    }
+}
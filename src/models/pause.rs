@@ -0,0 +1,26 @@
+use super::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PauseArguments {
+    pub thread_id: u64,
+}
+
+impl From<PauseArguments> for Value {
+    fn from(args: PauseArguments) -> Self {
+        let PauseArguments { thread_id } = args;
+
+        let thread_id = utils::attribute_u64("threadId", thread_id);
+
+        utils::finalize_object(thread_id)
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for PauseArguments {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let thread_id = utils::get_u64(map, "threadId")?;
+
+        Ok(Self { thread_id })
+    }
+}
@@ -0,0 +1,80 @@
+use super::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadMemoryArguments {
+    pub memory_reference: String,
+    pub offset: Option<i64>,
+    pub count: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadMemoryResponse {
+    pub address: String,
+    pub unreadable_bytes: Option<u64>,
+    /// Bytes read, encoded as base64 in the protocol.
+    pub data: Vec<u8>,
+}
+
+impl From<ReadMemoryArguments> for Value {
+    fn from(args: ReadMemoryArguments) -> Self {
+        let ReadMemoryArguments {
+            memory_reference,
+            offset,
+            count,
+        } = args;
+
+        let memory_reference = utils::attribute_string("memoryReference", memory_reference);
+        let offset = utils::attribute_i64_optional("offset", offset);
+        let count = utils::attribute_u64("count", count);
+
+        utils::finalize_object(memory_reference.chain(offset).chain(count))
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for ReadMemoryArguments {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let memory_reference = utils::get_string(map, "memoryReference")?;
+        let offset = utils::get_i64_optional(map, "offset")?;
+        let count = utils::get_u64(map, "count")?;
+
+        Ok(Self {
+            memory_reference,
+            offset,
+            count,
+        })
+    }
+}
+
+impl From<ReadMemoryResponse> for Value {
+    fn from(response: ReadMemoryResponse) -> Self {
+        let ReadMemoryResponse {
+            address,
+            unreadable_bytes,
+            data,
+        } = response;
+
+        let address = utils::attribute_string("address", address);
+        let unreadable_bytes = utils::attribute_u64_optional("unreadableBytes", unreadable_bytes);
+        let data = utils::attribute_base64_optional("data", data);
+
+        utils::finalize_object(address.chain(unreadable_bytes).chain(data))
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for ReadMemoryResponse {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let address = utils::get_string(map, "address")?;
+        let unreadable_bytes = utils::get_u64_optional(map, "unreadableBytes")?;
+        let data = utils::get_base64_optional(map, "data")?;
+
+        Ok(Self {
+            address,
+            unreadable_bytes,
+            data,
+        })
+    }
+}
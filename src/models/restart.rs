@@ -9,7 +9,18 @@ pub enum RestartArguments {
 impl From<RestartArguments> for Value {
     fn from(args: RestartArguments) -> Self {
         match args {
-            RestartArguments::Launch(l) => l.into(),
+            RestartArguments::Launch(l) => {
+                let no_debug = l.no_debug;
+                let mut launch = Value::from(l);
+
+                // `noDebug` is how the launch arguments are told apart from the attach arguments,
+                // so it is sent even if `false`
+                if let Some(map) = launch.as_object_mut() {
+                    map.insert("noDebug".into(), Value::Bool(no_debug));
+                }
+
+                launch
+            }
             RestartArguments::Attach(a) => a.into(),
         }
     }
@@ -19,6 +30,9 @@ impl TryFrom<&Map<String, Value>> for RestartArguments {
     type Error = Error;
 
     fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        // the arguments are only distinguishable by `noDebug`, that is always sent for launch. the
+        // adapter specific attributes are kept either way, so they can be interpreted by the
+        // backend
         let launch = map.contains_key("noDebug");
 
         if launch {
@@ -0,0 +1,26 @@
+use super::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RestartFrameArguments {
+    pub frame_id: u64,
+}
+
+impl From<RestartFrameArguments> for Value {
+    fn from(args: RestartFrameArguments) -> Self {
+        let RestartFrameArguments { frame_id } = args;
+
+        let frame_id = utils::attribute_u64("frameId", frame_id);
+
+        utils::finalize_object(frame_id)
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for RestartFrameArguments {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let frame_id = utils::get_u64(map, "frameId")?;
+
+        Ok(Self { frame_id })
+    }
+}
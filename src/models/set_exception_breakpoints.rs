@@ -0,0 +1,156 @@
+use super::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetExceptionBreakpointsArguments {
+    pub filters: Vec<String>,
+    pub filter_options: Vec<ExceptionFilterOptions>,
+    pub exception_options: Vec<ExceptionOptions>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExceptionFilterOptions {
+    pub filter_id: String,
+    pub condition: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExceptionOptions {
+    pub path: Vec<ExceptionPathSegment>,
+    pub break_mode: ExceptionBreakMode,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExceptionPathSegment {
+    pub negate: bool,
+    pub names: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetExceptionBreakpointsResponse {
+    pub breakpoints: Vec<Breakpoint>,
+}
+
+impl From<SetExceptionBreakpointsArguments> for Value {
+    fn from(args: SetExceptionBreakpointsArguments) -> Self {
+        let SetExceptionBreakpointsArguments {
+            filters,
+            filter_options,
+            exception_options,
+        } = args;
+
+        let filters = utils::attribute_array("filters", filters);
+        let filter_options = utils::attribute_array_optional("filterOptions", filter_options);
+        let exception_options =
+            utils::attribute_array_optional("exceptionOptions", exception_options);
+
+        utils::finalize_object(filters.chain(filter_options).chain(exception_options))
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for SetExceptionBreakpointsArguments {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let filters = utils::get_array_of_string(map, "filters")?;
+        let filter_options = utils::get_array_optional(map, "filterOptions")?;
+        let exception_options = utils::get_array_optional(map, "exceptionOptions")?;
+
+        Ok(Self {
+            filters,
+            filter_options,
+            exception_options,
+        })
+    }
+}
+
+impl From<ExceptionFilterOptions> for Value {
+    fn from(options: ExceptionFilterOptions) -> Self {
+        let ExceptionFilterOptions {
+            filter_id,
+            condition,
+        } = options;
+
+        let filter_id = utils::attribute_string("filterId", filter_id);
+        let condition = utils::attribute_string_optional("condition", condition);
+
+        utils::finalize_object(filter_id.chain(condition))
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for ExceptionFilterOptions {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let filter_id = utils::get_string(map, "filterId")?;
+        let condition = utils::get_string_optional(map, "condition")?;
+
+        Ok(Self {
+            filter_id,
+            condition,
+        })
+    }
+}
+
+impl From<ExceptionOptions> for Value {
+    fn from(options: ExceptionOptions) -> Self {
+        let ExceptionOptions { path, break_mode } = options;
+
+        let path = utils::attribute_array_optional("path", path);
+        let break_mode = utils::attribute_string("breakMode", break_mode);
+
+        utils::finalize_object(path.chain(break_mode))
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for ExceptionOptions {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let path = utils::get_array_optional(map, "path")?;
+        let break_mode = ExceptionBreakMode::try_from(utils::get_string(map, "breakMode")?)?;
+
+        Ok(Self { path, break_mode })
+    }
+}
+
+impl From<ExceptionPathSegment> for Value {
+    fn from(segment: ExceptionPathSegment) -> Self {
+        let ExceptionPathSegment { negate, names } = segment;
+
+        let negate = utils::attribute_bool_optional("negate", negate);
+        let names = utils::attribute_array("names", names);
+
+        utils::finalize_object(negate.chain(names))
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for ExceptionPathSegment {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let negate = utils::get_bool_optional(map, "negate")?;
+        let names = utils::get_array_of_string(map, "names")?;
+
+        Ok(Self { negate, names })
+    }
+}
+
+impl From<SetExceptionBreakpointsResponse> for Value {
+    fn from(args: SetExceptionBreakpointsResponse) -> Self {
+        let SetExceptionBreakpointsResponse { breakpoints } = args;
+
+        let breakpoints = utils::attribute_array_optional("breakpoints", breakpoints);
+
+        utils::finalize_object(breakpoints)
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for SetExceptionBreakpointsResponse {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let breakpoints = utils::get_array_optional(map, "breakpoints")?;
+
+        Ok(Self { breakpoints })
+    }
+}
@@ -0,0 +1,108 @@
+use super::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetExpressionArguments {
+    pub expression: String,
+    pub value: String,
+    pub frame_id: Option<u64>,
+    pub format: Option<ValueFormat>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetExpressionResponse {
+    pub value: String,
+    pub r#type: Option<String>,
+    pub presentation_hint: Option<VariablePresentationHint>,
+    pub variables_reference: Option<u64>,
+    pub named_variables: Option<u64>,
+    pub indexed_variables: Option<u64>,
+}
+
+impl From<SetExpressionArguments> for Value {
+    fn from(args: SetExpressionArguments) -> Self {
+        let SetExpressionArguments {
+            expression,
+            value,
+            frame_id,
+            format,
+        } = args;
+
+        let expression = utils::attribute_string("expression", expression);
+        let value = utils::attribute_string("value", value);
+        let frame_id = utils::attribute_u64_optional("frameId", frame_id);
+        let format = utils::attribute_optional("format", format);
+
+        utils::finalize_object(expression.chain(value).chain(frame_id).chain(format))
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for SetExpressionArguments {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let expression = utils::get_string(map, "expression")?;
+        let value = utils::get_string(map, "value")?;
+        let frame_id = utils::get_u64_optional(map, "frameId")?;
+        let format = utils::get_object_optional(map, "format")?;
+
+        Ok(Self {
+            expression,
+            value,
+            frame_id,
+            format,
+        })
+    }
+}
+
+impl From<SetExpressionResponse> for Value {
+    fn from(response: SetExpressionResponse) -> Self {
+        let SetExpressionResponse {
+            value,
+            r#type,
+            presentation_hint,
+            variables_reference,
+            named_variables,
+            indexed_variables,
+        } = response;
+
+        let value = utils::attribute_string("value", value);
+        let r#type = utils::attribute_string_optional("type", r#type);
+        let presentation_hint = utils::attribute_optional("presentationHint", presentation_hint);
+        let variables_reference =
+            utils::attribute_u64_optional("variablesReference", variables_reference);
+        let named_variables = utils::attribute_u64_optional("namedVariables", named_variables);
+        let indexed_variables =
+            utils::attribute_u64_optional("indexedVariables", indexed_variables);
+
+        utils::finalize_object(
+            value
+                .chain(r#type)
+                .chain(presentation_hint)
+                .chain(variables_reference)
+                .chain(named_variables)
+                .chain(indexed_variables),
+        )
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for SetExpressionResponse {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let value = utils::get_string(map, "value")?;
+        let r#type = utils::get_string_optional(map, "type")?;
+        let presentation_hint = utils::get_object_optional(map, "presentationHint")?;
+        let variables_reference = utils::get_u64_optional(map, "variablesReference")?;
+        let named_variables = utils::get_u64_optional(map, "namedVariables")?;
+        let indexed_variables = utils::get_u64_optional(map, "indexedVariables")?;
+
+        Ok(Self {
+            value,
+            r#type,
+            presentation_hint,
+            variables_reference,
+            named_variables,
+            indexed_variables,
+        })
+    }
+}
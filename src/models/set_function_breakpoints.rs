@@ -0,0 +1,90 @@
+use super::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetFunctionBreakpointsArguments {
+    pub breakpoints: Vec<FunctionBreakpoint>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionBreakpoint {
+    pub name: String,
+    pub condition: Option<String>,
+    pub hit_condition: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetFunctionBreakpointsResponse {
+    pub breakpoints: Vec<Breakpoint>,
+}
+
+impl From<SetFunctionBreakpointsArguments> for Value {
+    fn from(args: SetFunctionBreakpointsArguments) -> Self {
+        let SetFunctionBreakpointsArguments { breakpoints } = args;
+
+        let breakpoints = utils::attribute_array("breakpoints", breakpoints);
+
+        utils::finalize_object(breakpoints)
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for SetFunctionBreakpointsArguments {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let breakpoints = utils::get_array_of_object(map, "breakpoints")?;
+
+        Ok(Self { breakpoints })
+    }
+}
+
+impl From<FunctionBreakpoint> for Value {
+    fn from(args: FunctionBreakpoint) -> Self {
+        let FunctionBreakpoint {
+            name,
+            condition,
+            hit_condition,
+        } = args;
+
+        let name = utils::attribute_string("name", name);
+        let condition = utils::attribute_string_optional("condition", condition);
+        let hit_condition = utils::attribute_string_optional("hitCondition", hit_condition);
+
+        utils::finalize_object(name.chain(condition).chain(hit_condition))
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for FunctionBreakpoint {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let name = utils::get_string(map, "name")?;
+        let condition = utils::get_string_optional(map, "condition")?;
+        let hit_condition = utils::get_string_optional(map, "hitCondition")?;
+
+        Ok(Self {
+            name,
+            condition,
+            hit_condition,
+        })
+    }
+}
+
+impl From<SetFunctionBreakpointsResponse> for Value {
+    fn from(args: SetFunctionBreakpointsResponse) -> Self {
+        let SetFunctionBreakpointsResponse { breakpoints } = args;
+
+        let breakpoints = utils::attribute_array("breakpoints", breakpoints);
+
+        utils::finalize_object(breakpoints)
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for SetFunctionBreakpointsResponse {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let breakpoints = utils::get_array_of_object(map, "breakpoints")?;
+
+        Ok(Self { breakpoints })
+    }
+}
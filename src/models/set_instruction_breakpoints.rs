@@ -0,0 +1,101 @@
+use super::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetInstructionBreakpointsArguments {
+    pub breakpoints: Vec<InstructionBreakpoint>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstructionBreakpoint {
+    pub instruction_reference: String,
+    pub offset: Option<i64>,
+    pub condition: Option<String>,
+    pub hit_condition: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetInstructionBreakpointsResponse {
+    pub breakpoints: Vec<Breakpoint>,
+}
+
+impl From<SetInstructionBreakpointsArguments> for Value {
+    fn from(args: SetInstructionBreakpointsArguments) -> Self {
+        let SetInstructionBreakpointsArguments { breakpoints } = args;
+
+        let breakpoints = utils::attribute_array("breakpoints", breakpoints);
+
+        utils::finalize_object(breakpoints)
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for SetInstructionBreakpointsArguments {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let breakpoints = utils::get_array_of_object(map, "breakpoints")?;
+
+        Ok(Self { breakpoints })
+    }
+}
+
+impl From<InstructionBreakpoint> for Value {
+    fn from(args: InstructionBreakpoint) -> Self {
+        let InstructionBreakpoint {
+            instruction_reference,
+            offset,
+            condition,
+            hit_condition,
+        } = args;
+
+        let instruction_reference =
+            utils::attribute_string("instructionReference", instruction_reference);
+        let offset = utils::attribute_i64_optional("offset", offset);
+        let condition = utils::attribute_string_optional("condition", condition);
+        let hit_condition = utils::attribute_string_optional("hitCondition", hit_condition);
+
+        utils::finalize_object(
+            instruction_reference
+                .chain(offset)
+                .chain(condition)
+                .chain(hit_condition),
+        )
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for InstructionBreakpoint {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let instruction_reference = utils::get_string(map, "instructionReference")?;
+        let offset = utils::get_i64_optional(map, "offset")?;
+        let condition = utils::get_string_optional(map, "condition")?;
+        let hit_condition = utils::get_string_optional(map, "hitCondition")?;
+
+        Ok(Self {
+            instruction_reference,
+            offset,
+            condition,
+            hit_condition,
+        })
+    }
+}
+
+impl From<SetInstructionBreakpointsResponse> for Value {
+    fn from(args: SetInstructionBreakpointsResponse) -> Self {
+        let SetInstructionBreakpointsResponse { breakpoints } = args;
+
+        let breakpoints = utils::attribute_array("breakpoints", breakpoints);
+
+        utils::finalize_object(breakpoints)
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for SetInstructionBreakpointsResponse {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let breakpoints = utils::get_array_of_object(map, "breakpoints")?;
+
+        Ok(Self { breakpoints })
+    }
+}
@@ -0,0 +1,102 @@
+use super::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetVariableArguments {
+    pub variables_reference: u64,
+    pub name: String,
+    pub value: String,
+    pub format: Option<ValueFormat>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetVariableResponse {
+    pub value: String,
+    pub r#type: Option<String>,
+    pub variables_reference: Option<u64>,
+    pub named_variables: Option<u64>,
+    pub indexed_variables: Option<u64>,
+}
+
+impl From<SetVariableArguments> for Value {
+    fn from(args: SetVariableArguments) -> Self {
+        let SetVariableArguments {
+            variables_reference,
+            name,
+            value,
+            format,
+        } = args;
+
+        let variables_reference = utils::attribute_u64("variablesReference", variables_reference);
+        let name = utils::attribute_string("name", name);
+        let value = utils::attribute_string("value", value);
+        let format = utils::attribute_optional("format", format);
+
+        utils::finalize_object(variables_reference.chain(name).chain(value).chain(format))
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for SetVariableArguments {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let variables_reference = utils::get_u64(map, "variablesReference")?;
+        let name = utils::get_string(map, "name")?;
+        let value = utils::get_string(map, "value")?;
+        let format = utils::get_object_optional(map, "format")?;
+
+        Ok(Self {
+            variables_reference,
+            name,
+            value,
+            format,
+        })
+    }
+}
+
+impl From<SetVariableResponse> for Value {
+    fn from(response: SetVariableResponse) -> Self {
+        let SetVariableResponse {
+            value,
+            r#type,
+            variables_reference,
+            named_variables,
+            indexed_variables,
+        } = response;
+
+        let value = utils::attribute_string("value", value);
+        let r#type = utils::attribute_string_optional("type", r#type);
+        let variables_reference =
+            utils::attribute_u64_optional("variablesReference", variables_reference);
+        let named_variables = utils::attribute_u64_optional("namedVariables", named_variables);
+        let indexed_variables =
+            utils::attribute_u64_optional("indexedVariables", indexed_variables);
+
+        utils::finalize_object(
+            value
+                .chain(r#type)
+                .chain(variables_reference)
+                .chain(named_variables)
+                .chain(indexed_variables),
+        )
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for SetVariableResponse {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let value = utils::get_string(map, "value")?;
+        let r#type = utils::get_string_optional(map, "type")?;
+        let variables_reference = utils::get_u64_optional(map, "variablesReference")?;
+        let named_variables = utils::get_u64_optional(map, "namedVariables")?;
+        let indexed_variables = utils::get_u64_optional(map, "indexedVariables")?;
+
+        Ok(Self {
+            value,
+            r#type,
+            variables_reference,
+            named_variables,
+            indexed_variables,
+        })
+    }
+}
@@ -0,0 +1,63 @@
+use super::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceArguments {
+    pub source: Option<Source>,
+    pub source_reference: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceResponse {
+    pub content: String,
+    pub mime_type: Option<String>,
+}
+
+impl From<SourceArguments> for Value {
+    fn from(args: SourceArguments) -> Self {
+        let SourceArguments {
+            source,
+            source_reference,
+        } = args;
+
+        let source = utils::attribute_optional("source", source);
+        let source_reference = utils::attribute_u32("sourceReference", source_reference);
+
+        utils::finalize_object(source.chain(source_reference))
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for SourceArguments {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let source = utils::get_object_optional(map, "source")?;
+        let source_reference = utils::get_u32(map, "sourceReference")?;
+
+        Ok(Self {
+            source,
+            source_reference,
+        })
+    }
+}
+
+impl From<SourceResponse> for Value {
+    fn from(response: SourceResponse) -> Self {
+        let SourceResponse { content, mime_type } = response;
+
+        let content = utils::attribute_string("content", content);
+        let mime_type = utils::attribute_string_optional("mimeType", mime_type);
+
+        utils::finalize_object(content.chain(mime_type))
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for SourceResponse {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let content = utils::get_string(map, "content")?;
+        let mime_type = utils::get_string_optional(map, "mimeType")?;
+
+        Ok(Self { content, mime_type })
+    }
+}
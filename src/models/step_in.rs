@@ -0,0 +1,53 @@
+use super::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StepInArguments {
+    pub thread_id: u64,
+    pub single_thread: bool,
+    pub target_id: Option<u64>,
+    pub granularity: Option<SteppingGranularity>,
+}
+
+impl From<StepInArguments> for Value {
+    fn from(args: StepInArguments) -> Self {
+        let StepInArguments {
+            thread_id,
+            single_thread,
+            target_id,
+            granularity,
+        } = args;
+
+        let thread_id = utils::attribute_u64("threadId", thread_id);
+        let single_thread = utils::attribute_bool_optional("singleThread", single_thread);
+        let target_id = utils::attribute_u64_optional("targetId", target_id);
+        let granularity = utils::attribute_string_optional("granularity", granularity);
+
+        utils::finalize_object(
+            thread_id
+                .chain(single_thread)
+                .chain(target_id)
+                .chain(granularity),
+        )
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for StepInArguments {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let thread_id = utils::get_u64(map, "threadId")?;
+        let single_thread = utils::get_bool_optional(map, "singleThread")?;
+        let target_id = utils::get_u64_optional(map, "targetId")?;
+
+        let granularity = utils::get_string_optional(map, "granularity")?
+            .map(SteppingGranularity::try_from)
+            .transpose()?;
+
+        Ok(Self {
+            thread_id,
+            single_thread,
+            target_id,
+            granularity,
+        })
+    }
+}
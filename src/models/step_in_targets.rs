@@ -0,0 +1,111 @@
+use super::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StepInTargetsArguments {
+    pub frame_id: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StepInTargetsResponse {
+    pub targets: Vec<StepInTarget>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StepInTarget {
+    pub id: u64,
+    pub label: String,
+    pub line: Option<u64>,
+    pub column: Option<u64>,
+    pub end_line: Option<u64>,
+    pub end_column: Option<u64>,
+}
+
+impl From<StepInTargetsArguments> for Value {
+    fn from(args: StepInTargetsArguments) -> Self {
+        let StepInTargetsArguments { frame_id } = args;
+
+        let frame_id = utils::attribute_u64("frameId", frame_id);
+
+        utils::finalize_object(frame_id)
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for StepInTargetsArguments {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let frame_id = utils::get_u64(map, "frameId")?;
+
+        Ok(Self { frame_id })
+    }
+}
+
+impl From<StepInTargetsResponse> for Value {
+    fn from(response: StepInTargetsResponse) -> Self {
+        let StepInTargetsResponse { targets } = response;
+
+        let targets = utils::attribute_array("targets", targets);
+
+        utils::finalize_object(targets)
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for StepInTargetsResponse {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let targets = utils::get_array_of_object(map, "targets")?;
+
+        Ok(Self { targets })
+    }
+}
+
+impl From<StepInTarget> for Value {
+    fn from(target: StepInTarget) -> Self {
+        let StepInTarget {
+            id,
+            label,
+            line,
+            column,
+            end_line,
+            end_column,
+        } = target;
+
+        let id = utils::attribute_u64("id", id);
+        let label = utils::attribute_string("label", label);
+        let line = utils::attribute_u64_optional("line", line);
+        let column = utils::attribute_u64_optional("column", column);
+        let end_line = utils::attribute_u64_optional("endLine", end_line);
+        let end_column = utils::attribute_u64_optional("endColumn", end_column);
+
+        utils::finalize_object(
+            id.chain(label)
+                .chain(line)
+                .chain(column)
+                .chain(end_line)
+                .chain(end_column),
+        )
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for StepInTarget {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let id = utils::get_u64(map, "id")?;
+        let label = utils::get_string(map, "label")?;
+        let line = utils::get_u64_optional(map, "line")?;
+        let column = utils::get_u64_optional(map, "column")?;
+        let end_line = utils::get_u64_optional(map, "endLine")?;
+        let end_column = utils::get_u64_optional(map, "endColumn")?;
+
+        Ok(Self {
+            id,
+            label,
+            line,
+            column,
+            end_line,
+            end_column,
+        })
+    }
+}
@@ -0,0 +1,26 @@
+use super::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TerminateThreadsArguments {
+    pub thread_ids: Vec<u64>,
+}
+
+impl From<TerminateThreadsArguments> for Value {
+    fn from(args: TerminateThreadsArguments) -> Self {
+        let TerminateThreadsArguments { thread_ids } = args;
+
+        let thread_ids = utils::attribute_array_optional("threadIds", thread_ids);
+
+        utils::finalize_object(thread_ids)
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for TerminateThreadsArguments {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let thread_ids = utils::get_array_of_u64_optional(map, "threadIds")?;
+
+        Ok(Self { thread_ids })
+    }
+}
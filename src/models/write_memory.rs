@@ -0,0 +1,85 @@
+use super::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WriteMemoryArguments {
+    pub memory_reference: String,
+    pub offset: Option<i64>,
+    pub allow_partial: bool,
+    /// Bytes to write, encoded as base64 in the protocol.
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WriteMemoryResponse {
+    pub offset: Option<i64>,
+    pub bytes_written: Option<u64>,
+}
+
+impl From<WriteMemoryArguments> for Value {
+    fn from(args: WriteMemoryArguments) -> Self {
+        let WriteMemoryArguments {
+            memory_reference,
+            offset,
+            allow_partial,
+            data,
+        } = args;
+
+        let memory_reference = utils::attribute_string("memoryReference", memory_reference);
+        let offset = utils::attribute_i64_optional("offset", offset);
+        let allow_partial = utils::attribute_bool_optional("allowPartial", allow_partial);
+        let data = utils::attribute_base64("data", data);
+
+        utils::finalize_object(
+            memory_reference
+                .chain(offset)
+                .chain(allow_partial)
+                .chain(data),
+        )
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for WriteMemoryArguments {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let memory_reference = utils::get_string(map, "memoryReference")?;
+        let offset = utils::get_i64_optional(map, "offset")?;
+        let allow_partial = utils::get_bool_optional(map, "allowPartial")?;
+        let data = utils::get_base64(map, "data")?;
+
+        Ok(Self {
+            memory_reference,
+            offset,
+            allow_partial,
+            data,
+        })
+    }
+}
+
+impl From<WriteMemoryResponse> for Value {
+    fn from(response: WriteMemoryResponse) -> Self {
+        let WriteMemoryResponse {
+            offset,
+            bytes_written,
+        } = response;
+
+        let offset = utils::attribute_i64_optional("offset", offset);
+        let bytes_written = utils::attribute_u64_optional("bytesWritten", bytes_written);
+
+        utils::finalize_object(offset.chain(bytes_written))
+    }
+}
+
+impl TryFrom<&Map<String, Value>> for WriteMemoryResponse {
+    type Error = Error;
+
+    fn try_from(map: &Map<String, Value>) -> Result<Self, Self::Error> {
+        let offset = utils::get_i64_optional(map, "offset")?;
+        let bytes_written = utils::get_u64_optional(map, "bytesWritten")?;
+
+        Ok(Self {
+            offset,
+            bytes_written,
+        })
+    }
+}
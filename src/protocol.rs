@@ -270,6 +270,8 @@ impl ProtocolMessage {
     where
         R: io::Read,
     {
+        // buffering here would consume bytes beyond the message from the caller reader
+        #[allow(clippy::unbuffered_bytes)]
         let mut bytes = reader.bytes();
 
         let mut consumed = 0;
@@ -293,7 +295,7 @@ impl ProtocolMessage {
             // include the line break
             consumed += line.len() + 1;
 
-            let line = line.strip_suffix(&[b'\r']).unwrap_or(&line);
+            let line = line.strip_suffix(b"\r").unwrap_or(&line);
 
             let header = match str::from_utf8(line) {
                 Ok(h) => h.to_ascii_lowercase(),
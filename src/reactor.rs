@@ -1,28 +1,37 @@
 mod client;
+mod session;
+
+#[cfg(unix)]
+mod unix;
 
 #[cfg(test)]
 mod tests;
 
+use std::collections::HashMap;
 use std::io;
 use std::marker::PhantomData;
 use std::ops::Deref;
-use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
-use tokio::io::{AsyncBufReadExt, AsyncReadExt as _, AsyncWriteExt as _};
+use tokio::io::{AsyncRead, AsyncWrite};
 use tokio::net;
-use tokio::sync::{self, mpsc};
+use tokio::sync::mpsc::error::SendError;
+use tokio::sync::{self, oneshot};
 
+use crate::error::Error;
 use crate::event::Event;
-use crate::protocol::ProtocolMessage;
-use crate::request::{Request, ReverseRequest};
-use crate::response::Response;
+use crate::request::{Request, ReverseRequest, StartDebuggingRequestKind};
+use crate::response::{Response, ReverseResponse};
 
 pub use async_trait::async_trait;
 pub use client::*;
-pub use serde_json::Value;
+pub use serde_json::{Map, Value};
 pub use tokio::sync::mpsc::Sender;
 
+#[cfg(unix)]
+pub use unix::*;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ReactorReverseRequest {
     /// Id to be received in `response`.
@@ -40,15 +49,141 @@ impl From<ReverseRequest> for ReactorReverseRequest {
     }
 }
 
+/// Channel to deliver the reply of a reverse request awaited via [`ReverseRequester::send`].
+type ReverseResponseSender = oneshot::Sender<Result<ReverseResponse, Error>>;
+
+/// Reverse request to be sent by the session, with the channel to deliver its reply to.
+type QueuedReverseRequest = (ReactorReverseRequest, Option<ReverseResponseSender>);
+
+/// Reverse requests sent to the client, indexed by seq.
+type PendingReverseRequests = Mutex<HashMap<u64, Option<ReverseResponseSender>>>;
+
+/// Attribute of the configuration of a child session with the id of its parent.
+const PARENT_SESSION: &str = "__parentSession";
+
+/// Handle to submit reverse requests to the client.
+#[derive(Debug, Clone)]
+pub struct ReverseRequester {
+    session: u64,
+    requests: Sender<QueuedReverseRequest>,
+    pending: Arc<PendingReverseRequests>,
+    timeout: Option<Duration>,
+}
+
+impl ReverseRequester {
+    /// Id of the session, assigned by the reactor and unique within the process.
+    pub fn session(&self) -> u64 {
+        self.session
+    }
+
+    /// Set the maximum time [`ReverseRequester::send`] will wait for the reply of the client.
+    ///
+    /// By default, it will wait indefinitely.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout.replace(timeout);
+        self
+    }
+
+    /// Send a reverse request to the client and wait for its reply.
+    ///
+    /// If the session is closed before the client replies, a `BrokenPipe` error is returned.
+    pub async fn send<R>(&self, request: R) -> io::Result<ReverseResponse>
+    where
+        R: Into<ReverseRequest>,
+    {
+        let (tx, rx) = oneshot::channel();
+        let request = ReactorReverseRequest::from(request.into());
+
+        // the seq is picked by the session when the request is written
+        if self.requests.send((request, Some(tx))).await.is_err() {
+            return Err(io::ErrorKind::BrokenPipe.into());
+        }
+
+        let response = match self.timeout {
+            Some(timeout) => match tokio::time::timeout(timeout, rx).await {
+                Ok(response) => response,
+
+                Err(_) => {
+                    // the receiver is dropped, so the reply is no longer awaited
+                    self.pending
+                        .lock()
+                        .expect("pending reverse requests lock poisoned")
+                        .retain(|_, tx| !matches!(tx, Some(tx) if tx.is_closed()));
+
+                    return Err(io::ErrorKind::TimedOut.into());
+                }
+            },
+
+            None => rx.await,
+        };
+
+        match response {
+            Ok(response) => Ok(response?),
+
+            // the session was closed before the client replied
+            Err(_) => Err(io::ErrorKind::BrokenPipe.into()),
+        }
+    }
+
+    /// Ask the client to start a child session of this one, and wait for its reply.
+    ///
+    /// The id of this session is added to the configuration, so the backend of the child session
+    /// can link to it via [`Request::parent_session`].
+    ///
+    /// The client connects the child session the same way it did this one. A listener will serve
+    /// it in this process with a new backend, but under [`Reactor::stdio`] the client spawns a
+    /// new adapter process instead, so the parent session is not reachable from the child.
+    pub async fn start_debugging(
+        &self,
+        mut configuration: Map<String, Value>,
+        request: StartDebuggingRequestKind,
+    ) -> io::Result<ReverseResponse> {
+        configuration.insert(PARENT_SESSION.into(), Value::from(self.session));
+
+        self.send(ReverseRequest::StartDebugging {
+            configuration,
+            request,
+        })
+        .await
+    }
+
+    /// Submit a reverse request without waiting for its reply.
+    ///
+    /// The reply of the client will be delivered to `Backend::reverse_response`.
+    pub async fn submit<R>(&self, request: R) -> Result<(), SendError<ReactorReverseRequest>>
+    where
+        R: Into<ReactorReverseRequest>,
+    {
+        self.requests
+            .send((request.into(), None))
+            .await
+            .map_err(|SendError((request, _))| SendError(request))
+    }
+}
+
+impl Request {
+    /// Id of the session that started this one via [`ReverseRequester::start_debugging`].
+    ///
+    /// Available only in the `launch` and `attach` requests of a child session.
+    pub fn parent_session(&self) -> Option<u64> {
+        let attributes = match self {
+            Request::Launch { arguments } => &arguments.additional_attributes,
+            Request::Attach { arguments } => &arguments.additional_attributes,
+            _ => return None,
+        };
+
+        attributes.get(PARENT_SESSION).and_then(Value::as_u64)
+    }
+}
+
 #[async_trait]
 pub trait Backend {
     /// Initialize a new instance of a backend.
     ///
-    /// The provided mpsc senders will be listened by the reactor and forwarded to the client
+    /// The provided events sender will be listened by the reactor and forwarded to the client.
     ///
-    /// If an id is provided via `Option<u64>`, it will be the id of the response. Otherwise, the
-    /// reactor will pick the next available id.
-    async fn init(events: Sender<Event>, requests: Sender<ReactorReverseRequest>) -> Self;
+    /// Reverse requests are sent to the client via the provided [`ReverseRequester`].
+    async fn init(events: Sender<Event>, requests: ReverseRequester) -> Self;
 
     /// A request was sent by the client. It should be replied as response.
     ///
@@ -60,10 +195,126 @@ pub trait Backend {
     /// will not submit a response to the incoming request - this need to be used carefully because
     /// the client might end up in a dangling state for the protocol asks to always provide a
     /// response.
+    ///
+    /// Commands that aren't part of the protocol are received as [`Request::Custom`]. If the
+    /// backend doesn't reply to them, the reactor replies with [`Request::UNKNOWN_COMMAND`].
+    ///
+    /// If the client cancels the request, the future is dropped and the reactor replies to it
+    /// with a `cancelled` error.
     async fn request(&mut self, request: Request) -> Option<Response>;
 
-    /// The client replied to a reverse request
+    /// The client sent a response that doesn't match any reverse request sent by the reactor.
     async fn response(&mut self, id: u64, response: Response);
+
+    /// The client replied to a reverse request submitted via [`ReverseRequester::submit`].
+    async fn reverse_response(&mut self, id: u64, response: ReverseResponse) {
+        tracing::debug!("unhandled reverse response {}: {:?}", id, response);
+    }
+}
+
+/// A backend that processes the requests of the client concurrently.
+///
+/// Every request is spawned as its own task, and its response is sent to the client as soon as
+/// it is available. To be used with the reactor, it must be wrapped in [`Concurrent`].
+#[async_trait]
+pub trait ConcurrentBackend: Send + Sync + Sized + 'static {
+    /// Initialize a new instance of a backend.
+    ///
+    /// Behaves as [`Backend::init`].
+    async fn init(events: Sender<Event>, requests: ReverseRequester) -> Self;
+
+    /// A request was sent by the client. It should be replied as response.
+    ///
+    /// Behaves as [`Backend::request`].
+    async fn request(&self, request: Request) -> Option<Response>;
+
+    /// The client sent a response that doesn't match any reverse request sent by the reactor.
+    ///
+    /// As the requests, it is spawned as its own task, so it might run concurrently with them.
+    async fn response(&self, id: u64, response: Response);
+
+    /// The client replied to a reverse request submitted via [`ReverseRequester::submit`].
+    ///
+    /// As the requests, it is spawned as its own task, so it might run concurrently with them.
+    async fn reverse_response(&self, id: u64, response: ReverseResponse) {
+        tracing::debug!("unhandled reverse response {}: {:?}", id, response);
+    }
+
+    /// Check if the request must be strictly ordered.
+    ///
+    /// An ordered request will be processed only after all previously received requests are
+    /// completed, and the subsequent requests will wait for it to complete.
+    fn is_ordered(&self, request: &Request) -> bool {
+        let _ = request;
+
+        false
+    }
+}
+
+/// Marker to run a [`ConcurrentBackend`] with the reactor, as in `Reactor<Concurrent<B>>`.
+pub struct Concurrent<B> {
+    provider: PhantomData<B>,
+}
+
+/// Adapter to run a [`Backend`] as a [`ConcurrentBackend`].
+///
+/// Every request is ordered, so they are processed one at a time.
+pub struct Sequential<B> {
+    backend: sync::Mutex<B>,
+}
+
+#[async_trait]
+impl<B> ConcurrentBackend for Sequential<B>
+where
+    B: Backend + Send + 'static,
+{
+    async fn init(events: Sender<Event>, requests: ReverseRequester) -> Self {
+        let backend = sync::Mutex::new(B::init(events, requests).await);
+
+        Self { backend }
+    }
+
+    async fn request(&self, request: Request) -> Option<Response> {
+        self.backend.lock().await.request(request).await
+    }
+
+    async fn response(&self, id: u64, response: Response) {
+        self.backend.lock().await.response(id, response).await
+    }
+
+    async fn reverse_response(&self, id: u64, response: ReverseResponse) {
+        self.backend
+            .lock()
+            .await
+            .reverse_response(id, response)
+            .await
+    }
+
+    fn is_ordered(&self, _request: &Request) -> bool {
+        true
+    }
+}
+
+/// A backend that can be driven by the reactor.
+///
+/// Implemented for every [`Backend`], and for [`Concurrent`] over a [`ConcurrentBackend`].
+pub trait Handler: 'static {
+    /// Backend that will process the requests of a session.
+    type Backend: ConcurrentBackend;
+}
+
+impl<B> Handler for B
+where
+    B: Backend + Send + 'static,
+{
+    type Backend = Sequential<B>;
+}
+
+impl<B> Handler for Concurrent<B>
+where
+    B: ConcurrentBackend,
+{
+    type Backend = B;
 }
 
 pub struct Reactor<B> {
@@ -71,9 +322,18 @@ pub struct Reactor<B> {
     provider: PhantomData<B>,
 }
 
+impl<B> Default for Reactor<B>
+where
+    B: Handler,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<B> Reactor<B>
 where
-    B: Backend + Send,
+    B: Handler,
 {
     pub const fn new() -> Self {
         Self {
@@ -99,6 +359,34 @@ where
             provider: PhantomData,
         })
     }
+
+    /// Run a single backend session over the stdin/stdout of the process.
+    ///
+    /// This is the transport used by most editors, that will spawn the debug adapter as a child
+    /// process. The future will resolve when the client closes stdin.
+    ///
+    /// Stdout is reserved for the protocol messages, so any logging must be redirected to
+    /// stderr.
+    pub async fn stdio(&mut self) -> io::Result<()> {
+        tracing::info!("serving on stdio");
+
+        self.serve(tokio::io::stdin(), tokio::io::stdout()).await
+    }
+
+    /// Run a single backend session over arbitrary byte streams.
+    ///
+    /// The messages are framed with the same `Content-Length` headers used by the TCP listener,
+    /// so any transport (unix sockets, named pipes, TLS streams, in-memory duplex) can be used.
+    /// The future will resolve when the reader reaches EOF.
+    pub async fn serve<R, W>(&mut self, reader: R, writer: W) -> io::Result<()>
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+        W: AsyncWrite + Unpin + Send + 'static,
+    {
+        session::run::<B::Backend, _, _>(self.capacity, reader, writer).await;
+
+        Ok(())
+    }
 }
 
 pub struct ReactorListener<B> {
@@ -117,12 +405,10 @@ impl<B> Deref for ReactorListener<B> {
 
 impl<B> ReactorListener<B>
 where
-    B: Backend + Send,
+    B: Handler,
 {
     pub async fn listen(self) -> io::Result<()> {
-        let socket = self
-            .local_addr()
-            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        let socket = self.local_addr()?;
 
         tracing::info!("listening on {}", socket);
 
@@ -145,203 +431,11 @@ where
                     // treats such cases for us - as example, actix-server. it will distribute the
                     // incoming requests around a given number of workers
 
-                    let (events_tx, events_rx) = mpsc::channel::<Event>(capacity);
-                    let (requests_tx, requests_rx) =
-                        mpsc::channel::<ReactorReverseRequest>(capacity);
-                    let (mut events, mut requests) = (events_rx, requests_rx);
-
-                    // overflowing a seq in a DAP usage is not really feasible since the limit of
-                    // u64 is far beyond any normal usage. so we don't really need to put some
-                    // special guard here to check for overflows and we can just benefit from
-                    // atomic performance and security
-                    let seq_event = Arc::new(AtomicU64::new(1));
-                    let seq_request = Arc::clone(&seq_event);
-                    let seq_reverse = Arc::clone(&seq_event);
-
                     let (inbound, outbound) = stream.into_split();
 
-                    let outbound = sync::RwLock::new(outbound);
-                    let outbound_event = Arc::new(outbound);
-                    let outbound_request = Arc::clone(&outbound_event);
-                    let outbound_reverse = Arc::clone(&outbound_event);
-
-                    // thread to handle outbound events generated by the backend
-                    tokio::spawn(async move {
-                        let outbound = outbound_event;
-                        let seq = seq_event;
-
-                        while let Some(ev) = events.recv().await {
-                            let seq = seq.fetch_add(1, Ordering::SeqCst);
-
-                            let ev = ev.into_protocol(seq);
-                            let ev = ProtocolMessage::from(ev);
-                            let ev = ev.into_adapter_message();
-
-                            tracing::debug!("outbound {:?}", ev);
-
-                            if let Err(e) = outbound.write().await.write_all(ev.as_bytes()).await {
-                                tracing::error!("error sending event: {}", e);
-                            }
-                        }
-                    });
-
-                    // thread to handle reverse requests from the backend to the client
-                    tokio::spawn(async move {
-                        let seq = seq_reverse;
-                        let outbound = outbound_reverse;
-
-                        while let Some(re) = requests.recv().await {
-                            let seq = re.id.unwrap_or_else(|| seq.fetch_add(1, Ordering::SeqCst));
-                            let request = re.request.into_protocol(seq);
-                            let request = ProtocolMessage::from(request);
-                            let request = request.into_adapter_message();
-
-                            if let Err(e) =
-                                outbound.write().await.write_all(request.as_bytes()).await
-                            {
-                                tracing::error!("error sending reverse request: {}", e);
-                            }
-                        }
-                    });
-
-                    // thread to handle inbound requests to be processed by the backend
-                    tokio::spawn(async move {
-                        let mut backend = B::init(events_tx, requests_tx).await;
-                        let seq = seq_request;
-
-                        let mut buffer = tokio::io::BufReader::new(inbound);
-                        let outbound = outbound_request;
-
-                        loop {
-                            let len;
-                            let mut consumed = 0;
-
-                            // attempt to fetch content-length
-                            {
-                                let mut lines = (&mut buffer).lines();
-
-                                loop {
-                                    let line = match lines.next_line().await {
-                                        Ok(Some(l)) => l.to_ascii_lowercase(),
-                                        Ok(None) => return,
-                                        Err(_e) => return,
-                                    };
-
-                                    consumed += line.len() + 1;
-
-                                    let value = match line.trim_end_matches('\r').split_once(": ") {
-                                        Some((key, value)) if key == "content-length" => value,
-                                        _ => continue,
-                                    };
-
-                                    len = match value.parse::<usize>() {
-                                        Ok(n) => n,
-                                        Err(e) => {
-                                            tracing::warn!("invalid content-lenght: {}", e);
-                                            continue;
-                                        }
-                                    };
-
-                                    break;
-                                }
-
-                                // skip while line not empty
-                                loop {
-                                    let line = match lines.next_line().await {
-                                        Ok(Some(l)) => l,
-                                        _ => return,
-                                    };
-
-                                    consumed += line.len() + 1;
-
-                                    if line.trim_end_matches('\r').is_empty() {
-                                        break;
-                                    }
-                                }
-                            }
-
-                            let mut content = vec![0u8; len];
-
-                            if let Err(e) = buffer.read_exact(&mut content).await {
-                                tracing::warn!("couldn't read message len: {}", e);
-                                continue;
-                            }
-
-                            buffer.consume(len + consumed);
-
-                            let message = match ProtocolMessage::try_from_json_bytes(content) {
-                                Ok(m) => m,
-                                Err(e) => {
-                                    tracing::warn!("invalid message: {}", e);
-                                    continue;
-                                }
-                            };
-
-                            tracing::debug!("received message {:?}", message);
-
-                            let request = match message {
-                                ProtocolMessage::Request(re) => re,
-
-                                ProtocolMessage::Response(re) => {
-                                    let id = re.request_seq;
-
-                                    let response = match Response::try_from(&re) {
-                                        Ok(re) => re,
-                                        Err(e) => {
-                                            tracing::debug!(
-                                                "error parsing a response from the client: {}",
-                                                e
-                                            );
-                                            continue;
-                                        }
-                                    };
-
-                                    backend.response(id, response).await;
-                                    continue;
-                                }
-
-                                ProtocolMessage::Event(ev) => {
-                                    tracing::debug!(
-                                        "received unexpected event from client: {:?}",
-                                        ev
-                                    );
-                                    continue;
-                                }
-                            };
-
-                            let request_seq = request.seq;
-                            let request = match Request::try_from(&request) {
-                                Ok(re) => re,
-
-                                Err(e) => {
-                                    tracing::warn!("received invalid request from client: {}", e);
-                                    continue;
-                                }
-                            };
-
-                            let response = match backend.request(request).await {
-                                Some(re) => re,
-
-                                None => {
-                                    tracing::debug!("request didn't produce a response");
-                                    continue;
-                                }
-                            };
-
-                            let seq = seq.fetch_add(1, Ordering::SeqCst);
-                            let response = response.into_protocol(seq, request_seq);
-                            let response =
-                                ProtocolMessage::Response(response).into_adapter_message();
-
-                            tracing::debug!("outbound {:?}", response);
-
-                            if let Err(e) =
-                                outbound.write().await.write_all(response.as_bytes()).await
-                            {
-                                tracing::error!("error sending response: {}", e);
-                            }
-                        }
-                    });
+                    tokio::spawn(session::run::<B::Backend, _, _>(
+                        capacity, inbound, outbound,
+                    ));
                 }
 
                 Err(e) => tracing::error!("error accepting socket: {}", e),
@@ -0,0 +1,446 @@
+use std::collections::HashMap;
+use std::io;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt as _, AsyncWrite, AsyncWriteExt as _};
+use tokio::sync::{self, mpsc, watch};
+use tokio::task::JoinHandle;
+
+use super::{ConcurrentBackend, PendingReverseRequests, QueuedReverseRequest, ReverseRequester};
+use crate::event::Event;
+use crate::models::CancelArguments;
+use crate::protocol::{ProtocolMessage, ProtocolResponseError};
+use crate::request::Request;
+use crate::response::{Response, ReverseResponse};
+
+/// Id of the next session, unique within the process.
+static SESSION: AtomicU64 = AtomicU64::new(1);
+
+/// Run a backend session over the provided streams until the inbound stream is closed.
+pub async fn run<B, R, W>(capacity: usize, inbound: R, outbound: W)
+where
+    B: ConcurrentBackend,
+    R: AsyncRead + Unpin + Send + 'static,
+    W: AsyncWrite + Unpin + Send + 'static,
+{
+    let (events_tx, events_rx) = mpsc::channel::<Event>(capacity);
+    let (requests_tx, requests_rx) = mpsc::channel::<QueuedReverseRequest>(capacity);
+    let (mut events, mut requests) = (events_rx, requests_rx);
+
+    // overflowing a seq in a DAP usage is not really feasible since the limit of
+    // u64 is far beyond any normal usage. so we don't really need to put some
+    // special guard here to check for overflows and we can just benefit from
+    // atomic performance and security
+    let seq = Arc::new(AtomicU64::new(1));
+
+    let pending = Arc::new(PendingReverseRequests::default());
+    let requester = ReverseRequester {
+        session: SESSION.fetch_add(1, Ordering::SeqCst),
+        requests: requests_tx,
+        pending: Arc::clone(&pending),
+        timeout: None,
+    };
+    let pending_reverse = Arc::clone(&pending);
+
+    let outbound = Arc::new(Outbound {
+        writer: sync::Mutex::new(outbound),
+        seq,
+    });
+    let outbound_event = Arc::clone(&outbound);
+    let outbound_reverse = Arc::clone(&outbound);
+
+    // thread to handle outbound events generated by the backend
+    tokio::spawn(async move {
+        let outbound = outbound_event;
+
+        while let Some(ev) = events.recv().await {
+            outbound
+                .send("event", None, |seq| ev.into_protocol(seq).into())
+                .await;
+        }
+    });
+
+    // thread to handle reverse requests from the backend to the client
+    let reverse = tokio::spawn(async move {
+        let outbound = outbound_reverse;
+        let pending = pending_reverse;
+
+        while let Some((re, tx)) = requests.recv().await {
+            outbound
+                .send("reverse request", re.id, |seq| {
+                    // track the seq so the reply of the client can be parsed as a reverse
+                    // response. this must happen before the request is written, or the reply
+                    // might arrive first
+                    pending
+                        .lock()
+                        .expect("pending reverse requests lock poisoned")
+                        .insert(seq, tx);
+
+                    re.request.into_protocol(seq).into()
+                })
+                .await;
+        }
+    });
+
+    // handle inbound requests to be processed by the backend
+    let backend = Arc::new(B::init(events_tx, requester).await);
+
+    let mut buffer = tokio::io::BufReader::new(inbound);
+    let mut barrier = Barrier::default();
+    let in_flight = Arc::new(InFlight::default());
+
+    loop {
+        let content = match read_content(&mut buffer).await {
+            Some(Ok(c)) => c,
+            Some(Err(e)) => {
+                tracing::warn!("couldn't read message len: {}", e);
+                continue;
+            }
+            None => break,
+        };
+
+        let message = match ProtocolMessage::try_from_json_bytes(content) {
+            Ok(m) => m,
+            Err(e) => {
+                tracing::warn!("invalid message: {}", e);
+                continue;
+            }
+        };
+
+        tracing::debug!("received message {:?}", message);
+
+        let request = match message {
+            ProtocolMessage::Request(re) => re,
+
+            ProtocolMessage::Response(re) => {
+                let id = re.request_seq;
+
+                let pending = pending
+                    .lock()
+                    .expect("pending reverse requests lock poisoned")
+                    .remove(&id);
+
+                match pending {
+                    Some(Some(tx)) => {
+                        tx.send(ReverseResponse::try_from(&re)).ok();
+                        continue;
+                    }
+
+                    Some(None) => {
+                        match ReverseResponse::try_from(&re) {
+                            Ok(response) => {
+                                let backend = Arc::clone(&backend);
+
+                                // spawned as the requests, so a slow callback doesn't block them
+                                tokio::spawn(async move {
+                                    backend.reverse_response(id, response).await;
+                                });
+                            }
+
+                            Err(e) => {
+                                tracing::debug!("error parsing a reverse response: {}", e)
+                            }
+                        }
+
+                        continue;
+                    }
+
+                    None => (),
+                }
+
+                let response = match Response::try_from(&re) {
+                    Ok(re) => re,
+                    Err(e) => {
+                        tracing::debug!("error parsing a response from the client: {}", e);
+                        continue;
+                    }
+                };
+
+                let backend = Arc::clone(&backend);
+
+                tokio::spawn(async move {
+                    backend.response(id, response).await;
+                });
+
+                continue;
+            }
+
+            ProtocolMessage::Event(ev) => {
+                tracing::debug!("received unexpected event from client: {:?}", ev);
+                continue;
+            }
+        };
+
+        let request_seq = request.seq;
+        let command = request.command.clone();
+        let request = match Request::try_from(&request) {
+            Ok(re) => re,
+
+            Err(e) => {
+                tracing::warn!("received invalid request from client: {}", e);
+
+                // the client expects a reply for every request, even the ones it got wrong
+                let response = Response::from_error(command, e);
+
+                outbound
+                    .send("response", None, |seq| {
+                        response.into_protocol(seq, request_seq).into()
+                    })
+                    .await;
+
+                continue;
+            }
+        };
+
+        // the cancel request itself is still dispatched to the backend so it can reply to it and
+        // handle the cancellation of progresses
+        if let Request::Cancel {
+            arguments:
+                Some(CancelArguments {
+                    request_id: Some(id),
+                    ..
+                }),
+        } = &request
+        {
+            in_flight.cancel(&outbound, *id).await;
+        }
+
+        let (done, wait) = barrier.enter(backend.is_ordered(&request));
+        let backend = Arc::clone(&backend);
+        let outbound = Arc::clone(&outbound);
+        let in_flight_task = Arc::clone(&in_flight);
+
+        // the task will not complete before it is tracked because it must take the lock to
+        // release its entry
+        let mut requests = in_flight.lock();
+
+        let task = tokio::spawn(async move {
+            // signal the completion of the request when dropped
+            let _done = done;
+
+            for w in wait {
+                Barrier::wait(w).await;
+            }
+
+            let custom = matches!(request, Request::Custom { .. });
+            let response = backend.request(request).await;
+
+            // a cancelled request is no longer tracked, and its error response was already sent
+            let command = match in_flight_task.lock().remove(&request_seq) {
+                Some(re) => re.command,
+                None => return,
+            };
+
+            let response = match response {
+                Some(re) => re,
+
+                // the client would wait forever for the reply of a command the backend ignored
+                None if custom => Response::from_error(command, Request::UNKNOWN_COMMAND),
+
+                None => {
+                    tracing::debug!("request didn't produce a response");
+                    return;
+                }
+            };
+
+            outbound
+                .send("response", None, |seq| {
+                    response.into_protocol(seq, request_seq).into()
+                })
+                .await;
+        });
+
+        requests.insert(request_seq, InFlightRequest { command, task });
+        drop(requests);
+    }
+
+    // the client will not reply to the reverse requests anymore. once the requests are no longer
+    // received, the pending replies are dropped so whoever awaits them gets an error
+    reverse.abort();
+    reverse.await.ok();
+
+    pending
+        .lock()
+        .expect("pending reverse requests lock poisoned")
+        .clear();
+}
+
+/// Read the content of the next message, returning `None` if the stream is closed.
+async fn read_content<R>(buffer: &mut tokio::io::BufReader<R>) -> Option<io::Result<Vec<u8>>>
+where
+    R: AsyncRead + Unpin,
+{
+    let len;
+
+    // attempt to fetch content-length
+    {
+        let mut lines = (&mut *buffer).lines();
+
+        loop {
+            let line = match lines.next_line().await {
+                Ok(Some(l)) => l.to_ascii_lowercase(),
+                Ok(None) => return None,
+                Err(_e) => return None,
+            };
+
+            let value = match line.trim_end_matches('\r').split_once(": ") {
+                Some(("content-length", value)) => value,
+                _ => continue,
+            };
+
+            len = match value.parse::<usize>() {
+                Ok(n) => n,
+                Err(e) => {
+                    tracing::warn!("invalid content-lenght: {}", e);
+                    continue;
+                }
+            };
+
+            break;
+        }
+
+        // skip while line not empty
+        loop {
+            let line = match lines.next_line().await {
+                Ok(Some(l)) => l,
+                _ => return None,
+            };
+
+            if line.trim_end_matches('\r').is_empty() {
+                break;
+            }
+        }
+    }
+
+    let mut content = vec![0u8; len];
+
+    Some(buffer.read_exact(&mut content).await.map(|_| content))
+}
+
+/// Outbound stream of the session.
+struct Outbound<W> {
+    writer: sync::Mutex<W>,
+    seq: Arc<AtomicU64>,
+}
+
+impl<W> Outbound<W>
+where
+    W: AsyncWrite + Unpin,
+{
+    /// Write a message to the client.
+    ///
+    /// If `seq` is `None`, the next available seq is picked while holding the writer, so the
+    /// messages are sent in the order of their seqs.
+    async fn send<F>(&self, kind: &str, seq: Option<u64>, message: F)
+    where
+        F: FnOnce(u64) -> ProtocolMessage,
+    {
+        let mut writer = self.writer.lock().await;
+
+        let seq = seq.unwrap_or_else(|| self.seq.fetch_add(1, Ordering::SeqCst));
+        let message = message(seq).into_adapter_message();
+
+        tracing::debug!("outbound {:?}", message);
+
+        // stdout and other buffered writers will hold the message until flushed
+        let result = match writer.write_all(message.as_bytes()).await {
+            Ok(()) => writer.flush().await,
+            Err(e) => Err(e),
+        };
+
+        if let Err(e) = result {
+            tracing::error!("error sending {}: {}", kind, e);
+        }
+    }
+}
+
+/// Request dispatched to the backend that is yet to be replied.
+struct InFlightRequest {
+    command: String,
+    task: JoinHandle<()>,
+}
+
+/// Requests dispatched to the backend, indexed by seq.
+///
+/// Whoever removes the entry of a request is responsible for replying to it, so a request that
+/// completes concurrently with its cancellation is replied only once.
+#[derive(Default)]
+struct InFlight {
+    requests: Mutex<HashMap<u64, InFlightRequest>>,
+}
+
+impl InFlight {
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<u64, InFlightRequest>> {
+        self.requests
+            .lock()
+            .expect("in-flight requests lock poisoned")
+    }
+
+    /// Abort the request of the provided seq, replying to it with a cancelled error.
+    async fn cancel<W>(&self, outbound: &Outbound<W>, request_seq: u64)
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let InFlightRequest { command, task } = match self.lock().remove(&request_seq) {
+            Some(re) => re,
+            None => {
+                tracing::debug!("request {} is not in flight", request_seq);
+                return;
+            }
+        };
+
+        task.abort();
+
+        let response = Response::Error {
+            command,
+            error: ProtocolResponseError {
+                message: Some("cancelled".into()),
+                body: None,
+            },
+        };
+
+        outbound
+            .send("response", None, |seq| {
+                response.into_protocol(seq, request_seq).into()
+            })
+            .await;
+    }
+}
+
+/// Ordering of the requests dispatched to the backend.
+///
+/// Unordered requests will wait only for the last ordered request, while ordered requests will
+/// wait for every request received before them.
+#[derive(Default)]
+struct Barrier {
+    ordered: Option<watch::Receiver<()>>,
+    unordered: Vec<watch::Receiver<()>>,
+}
+
+impl Barrier {
+    /// Register a new request, returning its completion signal and the signals it must wait for.
+    fn enter(&mut self, ordered: bool) -> (watch::Sender<()>, Vec<watch::Receiver<()>>) {
+        let (done_tx, done) = watch::channel(());
+        let mut wait = Vec::new();
+
+        wait.extend(self.ordered.clone());
+
+        if ordered {
+            wait.append(&mut self.unordered);
+            self.ordered.replace(done);
+        } else {
+            // discard the signals of the requests that are already completed
+            self.unordered.retain(|d| d.has_changed().is_ok());
+            self.unordered.push(done);
+        }
+
+        (done_tx, wait)
+    }
+
+    /// Wait until the request of the signal is completed.
+    async fn wait(mut done: watch::Receiver<()>) {
+        // no value is ever sent, so this will resolve only when the sender is dropped
+        while done.changed().await.is_ok() {}
+    }
+}
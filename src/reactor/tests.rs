@@ -1,4 +1,7 @@
 use std::io;
+use std::time::Duration;
+
+use tokio::io::{AsyncRead, AsyncReadExt as _, AsyncWriteExt as _};
 
 use crate::prelude::*;
 
@@ -12,7 +15,14 @@ impl Service {
             supports_conditional_breakpoints: true,
             supports_hit_conditional_breakpoints: true,
             supports_evaluate_for_hovers: true,
-            exception_breakpoint_filters: vec![],
+            exception_breakpoint_filters: vec![ExceptionBreakpointsFilter {
+                filter: "uncaught".into(),
+                label: "Uncaught Exceptions".into(),
+                description: None,
+                default: true,
+                supports_condition: false,
+                condition_description: None,
+            }],
             supports_step_back: true,
             supports_set_variable: false,
             supports_restart_frame: false,
@@ -57,7 +67,7 @@ impl Service {
 
 #[async_trait]
 impl Backend for Service {
-    async fn init(_events: Sender<Event>, _requests: Sender<ReactorReverseRequest>) -> Self {
+    async fn init(_events: Sender<Event>, _requests: ReverseRequester) -> Self {
         Self
     }
 
@@ -75,6 +85,217 @@ impl Backend for Service {
     async fn response(&mut self, _id: u64, _response: Response) {}
 }
 
+struct Terminal {
+    events: Sender<Event>,
+    requests: ReverseRequester,
+}
+
+impl Terminal {
+    fn run_in_terminal() -> ReverseRequest {
+        ReverseRequest::RunInTerminal {
+            kind: None,
+            title: None,
+            cwd: "/".into(),
+            args: vec!["foo".into()],
+            env: None,
+            args_can_be_interpreted_by_shell: false,
+        }
+    }
+
+    async fn exit(events: &Sender<Event>, response: ReverseResponse) {
+        if let ReverseResponse::RunInTerminal {
+            process_id: Some(process_id),
+            ..
+        } = response
+        {
+            let exit_code = process_id as u64;
+
+            events.send(Event::Exited { exit_code }).await.ok();
+        }
+    }
+}
+
+#[async_trait]
+impl Backend for Terminal {
+    async fn init(events: Sender<Event>, requests: ReverseRequester) -> Self {
+        let requests = requests.with_timeout(Duration::from_secs(5));
+
+        Self { events, requests }
+    }
+
+    async fn request(&mut self, request: Request) -> Option<Response> {
+        match request {
+            Request::Launch { .. } => {
+                if let Ok(response) = self.requests.send(Self::run_in_terminal()).await {
+                    Self::exit(&self.events, response).await;
+                }
+
+                Some(Response::Launch)
+            }
+
+            Request::Attach { .. } => {
+                self.requests.submit(Self::run_in_terminal()).await.ok();
+
+                Some(Response::Attach)
+            }
+
+            _ => None,
+        }
+    }
+
+    async fn reverse_response(&mut self, _id: u64, response: ReverseResponse) {
+        Self::exit(&self.events, response).await;
+    }
+
+    async fn response(&mut self, _id: u64, _response: Response) {}
+}
+
+/// Reports the result of the reverse request sent on launch, that is never replied.
+static ORPHANED: std::sync::Mutex<
+    Option<tokio::sync::oneshot::Sender<io::Result<ReverseResponse>>>,
+> = std::sync::Mutex::new(None);
+
+struct Orphan {
+    requests: ReverseRequester,
+}
+
+#[async_trait]
+impl Backend for Orphan {
+    async fn init(_events: Sender<Event>, requests: ReverseRequester) -> Self {
+        Self { requests }
+    }
+
+    async fn request(&mut self, request: Request) -> Option<Response> {
+        match request {
+            Request::Launch { .. } => {
+                let response = self.requests.send(Terminal::run_in_terminal()).await;
+
+                if let Some(tx) = ORPHANED.lock().expect("orphaned lock poisoned").take() {
+                    tx.send(response).ok();
+                }
+
+                Some(Response::Launch)
+            }
+
+            _ => None,
+        }
+    }
+
+    async fn response(&mut self, _id: u64, _response: Response) {}
+}
+
+/// Sessions that started a child session.
+static FORKED: std::sync::Mutex<Vec<u64>> = std::sync::Mutex::new(Vec::new());
+
+/// Starts a child session for every launch that isn't itself a child.
+struct Forking {
+    requests: ReverseRequester,
+}
+
+#[async_trait]
+impl Backend for Forking {
+    async fn init(_events: Sender<Event>, requests: ReverseRequester) -> Self {
+        let requests = requests.with_timeout(Duration::from_secs(5));
+
+        Self { requests }
+    }
+
+    async fn request(&mut self, request: Request) -> Option<Response> {
+        if let Some(parent) = request.parent_session() {
+            let forked = FORKED
+                .lock()
+                .expect("forked lock poisoned")
+                .contains(&parent);
+
+            if forked && parent != self.requests.session() {
+                return Some(Response::Launch);
+            }
+
+            return Some(Response::Error {
+                command: "launch".into(),
+                error: ProtocolResponseError {
+                    message: Some("unknown parent session".into()),
+                    body: None,
+                },
+            });
+        }
+
+        match request {
+            Request::Launch { .. } => {
+                FORKED
+                    .lock()
+                    .expect("forked lock poisoned")
+                    .push(self.requests.session());
+
+                let response = self
+                    .requests
+                    .start_debugging(Map::new(), StartDebuggingRequestKind::Launch)
+                    .await;
+
+                match response {
+                    Ok(ReverseResponse::StartDebugging) => Some(Response::Launch),
+                    _ => None,
+                }
+            }
+
+            _ => None,
+        }
+    }
+
+    async fn response(&mut self, _id: u64, _response: Response) {}
+}
+
+struct Slow;
+
+#[async_trait]
+impl ConcurrentBackend for Slow {
+    async fn init(_events: Sender<Event>, _requests: ReverseRequester) -> Self {
+        Self
+    }
+
+    async fn request(&self, request: Request) -> Option<Response> {
+        match request {
+            Request::Evaluate { arguments } if arguments.expression == "forever" => {
+                std::future::pending().await
+            }
+
+            Request::Evaluate { arguments } => {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+
+                Some(Response::Evaluate {
+                    body: EvaluateResponse {
+                        result: arguments.expression,
+                        r#type: None,
+                        presentation_hint: None,
+                        variables_reference: 0,
+                        named_variables: None,
+                        indexed_variables: None,
+                        memory_reference: None,
+                    },
+                })
+            }
+
+            Request::Threads => Some(Response::Threads {
+                body: ThreadsResponse { threads: vec![] },
+            }),
+
+            Request::Disconnect { .. } => Some(Response::Disconnect),
+
+            Request::Cancel { .. } => Some(Response::Cancel),
+
+            _ => None,
+        }
+    }
+
+    async fn response(&self, _id: u64, _response: Response) {
+        std::future::pending().await
+    }
+
+    fn is_ordered(&self, request: &Request) -> bool {
+        matches!(request, Request::Disconnect { .. })
+    }
+}
+
 #[tokio::test]
 async fn initialize_works() -> io::Result<()> {
     let reactor = Reactor::<Service>::new()
@@ -94,25 +315,7 @@ async fn initialize_works() -> io::Result<()> {
         .requests
         .send(ClientRequest {
             seq: None,
-            request: Request::Initialize {
-                arguments: InitializeArguments {
-                    client_id: None,
-                    client_name: None,
-                    adapter_id: "foo".into(),
-                    locale: None,
-                    lines_start_at_1: true,
-                    column_start_at_1: true,
-                    path_format: None,
-                    supports_variable_type: true,
-                    supports_variable_paging: false,
-                    supports_run_in_terminal_request: false,
-                    supports_memory_references: false,
-                    supports_progress_reporting: false,
-                    supports_invalidated_event: false,
-                    supports_memory_event: false,
-                    supports_args_can_be_interpreted_by_shell: false,
-                },
-            },
+            request: initialize(),
         })
         .await
         .expect("failed to submit request");
@@ -125,13 +328,624 @@ async fn initialize_works() -> io::Result<()> {
 
     let capabilities = match re.response {
         Response::Initialize { body } => Ok(body),
-        _ => Err(io::Error::new(
-            io::ErrorKind::Other,
-            "wrong response variant",
-        )),
+        _ => Err(io::Error::other("wrong response variant")),
     }?;
 
     assert_eq!(Service::capabilities(), capabilities);
 
     Ok(())
 }
+
+#[tokio::test]
+async fn serve_works() -> io::Result<()> {
+    let (client, server) = tokio::io::duplex(1024);
+    let (reader, writer) = tokio::io::split(server);
+
+    tokio::spawn(async move {
+        Reactor::<Service>::new().serve(reader, writer).await.ok();
+    });
+
+    let (mut client_reader, mut client_writer) = tokio::io::split(client);
+
+    // both requests are sent at once so the reactor must not discard buffered messages
+    let message = [1, 2]
+        .into_iter()
+        .map(|seq| ProtocolMessage::from(initialize().into_protocol(seq)).into_adapter_message())
+        .collect::<String>();
+
+    client_writer.write_all(message.as_bytes()).await?;
+
+    let mut buffer = Vec::new();
+
+    for request_seq in [1, 2] {
+        let response = match read_message(&mut client_reader, &mut buffer).await? {
+            ProtocolMessage::Response(re) => re,
+            _ => return Err(io::Error::other("a response was expected")),
+        };
+
+        assert_eq!(request_seq, response.request_seq);
+
+        let capabilities = match Response::try_from(&response)? {
+            Response::Initialize { body } => Ok(body),
+            _ => Err(io::Error::other("wrong response variant")),
+        }?;
+
+        assert_eq!(Service::capabilities(), capabilities);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn reverse_request_works() -> io::Result<()> {
+    let launch = Request::Launch {
+        arguments: LaunchArguments {
+            no_debug: false,
+            restart: None,
+            additional_attributes: Default::default(),
+        },
+    };
+
+    run_in_terminal(launch).await
+}
+
+#[tokio::test]
+async fn reverse_response_works() -> io::Result<()> {
+    let attach = Request::Attach {
+        arguments: AttachArguments {
+            restart: None,
+            additional_attributes: Default::default(),
+        },
+    };
+
+    run_in_terminal(attach).await
+}
+
+#[tokio::test]
+async fn concurrent_backend_works() -> io::Result<()> {
+    let (client, server) = tokio::io::duplex(1024);
+    let (reader, writer) = tokio::io::split(server);
+
+    tokio::spawn(async move {
+        Reactor::<Concurrent<Slow>>::new()
+            .serve(reader, writer)
+            .await
+            .ok();
+    });
+
+    let (mut client_reader, mut client_writer) = tokio::io::split(client);
+
+    let evaluate = Request::Evaluate {
+        arguments: EvaluateArguments {
+            expression: "slow".into(),
+            frame_id: None,
+            context: None,
+            format: None,
+        },
+    };
+
+    let disconnect = Request::Disconnect { arguments: None };
+
+    let message = [
+        (1, evaluate),
+        (2, Request::Threads),
+        (3, disconnect),
+        (4, Request::Threads),
+    ]
+    .into_iter()
+    .map(|(seq, re)| ProtocolMessage::from(re.into_protocol(seq)).into_adapter_message())
+    .collect::<String>();
+
+    client_writer.write_all(message.as_bytes()).await?;
+
+    let mut buffer = Vec::new();
+    let mut seq = 0;
+
+    // the slow request doesn't block the next one, but the ordered request waits for both
+    for request_seq in [2, 1, 3, 4] {
+        let response = match read_message(&mut client_reader, &mut buffer).await? {
+            ProtocolMessage::Response(re) => re,
+            _ => return Err(io::Error::other("a response was expected")),
+        };
+
+        assert_eq!(request_seq, response.request_seq);
+        assert!(seq < response.seq);
+
+        seq = response.seq;
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn response_callback_works() -> io::Result<()> {
+    let (client, server) = tokio::io::duplex(1024);
+    let (reader, writer) = tokio::io::split(server);
+
+    tokio::spawn(async move {
+        Reactor::<Concurrent<Slow>>::new()
+            .serve(reader, writer)
+            .await
+            .ok();
+    });
+
+    let (mut client_reader, mut client_writer) = tokio::io::split(client);
+
+    // the backend never completes the handling of the response
+    let response = ProtocolMessage::from(Response::Launch.into_protocol(1, 1));
+    let threads = ProtocolMessage::from(Request::Threads.into_protocol(2));
+
+    let message = [response, threads]
+        .into_iter()
+        .map(ProtocolMessage::into_adapter_message)
+        .collect::<String>();
+
+    client_writer.write_all(message.as_bytes()).await?;
+
+    let response = tokio::time::timeout(
+        Duration::from_secs(5),
+        read_message(&mut client_reader, &mut Vec::new()),
+    )
+    .await
+    .map_err(|_| io::Error::other("the request was blocked by the response"))??;
+
+    let response = match response {
+        ProtocolMessage::Response(re) => re,
+        _ => return Err(io::Error::other("a response was expected")),
+    };
+
+    assert_eq!(2, response.request_seq);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn cancel_request_works() -> io::Result<()> {
+    let (client, server) = tokio::io::duplex(1024);
+    let (reader, writer) = tokio::io::split(server);
+
+    tokio::spawn(async move {
+        Reactor::<Concurrent<Slow>>::new()
+            .serve(reader, writer)
+            .await
+            .ok();
+    });
+
+    let (mut client_reader, mut client_writer) = tokio::io::split(client);
+
+    let evaluate = Request::Evaluate {
+        arguments: EvaluateArguments {
+            expression: "forever".into(),
+            frame_id: None,
+            context: None,
+            format: None,
+        },
+    };
+
+    let cancel = Request::Cancel {
+        arguments: Some(CancelArguments {
+            request_id: Some(1),
+            progress_id: None,
+        }),
+    };
+
+    let message = [(1, evaluate), (2, cancel)]
+        .into_iter()
+        .map(|(seq, re)| ProtocolMessage::from(re.into_protocol(seq)).into_adapter_message())
+        .collect::<String>();
+
+    client_writer.write_all(message.as_bytes()).await?;
+
+    let mut buffer = Vec::new();
+
+    let cancelled = match read_message(&mut client_reader, &mut buffer).await? {
+        ProtocolMessage::Response(re) => re,
+        _ => return Err(io::Error::other("a response was expected")),
+    };
+
+    assert_eq!(1, cancelled.request_seq);
+    assert_eq!("evaluate", cancelled.command);
+    assert_eq!(
+        Some("cancelled"),
+        cancelled
+            .result
+            .as_ref()
+            .err()
+            .and_then(|e| e.message.as_deref())
+    );
+
+    let response = match read_message(&mut client_reader, &mut buffer).await? {
+        ProtocolMessage::Response(re) => re,
+        _ => return Err(io::Error::other("a response was expected")),
+    };
+
+    assert_eq!(2, response.request_seq);
+    assert_eq!("cancel", response.command);
+    assert!(response.result.is_ok());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn invalid_request_works() -> io::Result<()> {
+    let (client, server) = tokio::io::duplex(1024);
+    let (reader, writer) = tokio::io::split(server);
+
+    tokio::spawn(async move {
+        Reactor::<Service>::new().serve(reader, writer).await.ok();
+    });
+
+    let (mut client_reader, mut client_writer) = tokio::io::split(client);
+
+    let message = [(1, "evaluate"), (2, "unknownCommand")]
+        .into_iter()
+        .map(|(seq, command)| {
+            let request = ProtocolRequest {
+                seq,
+                command: command.into(),
+                arguments: None,
+            };
+
+            ProtocolMessage::from(request).into_adapter_message()
+        })
+        .collect::<String>();
+
+    client_writer.write_all(message.as_bytes()).await?;
+
+    let mut buffer = Vec::new();
+
+    let expected = [
+        (1, "evaluate", Error::new("arguments", Cause::IsMandatory)),
+        (2, "unknownCommand", Request::UNKNOWN_COMMAND),
+    ];
+
+    for (request_seq, command, error) in expected {
+        let response = match read_message(&mut client_reader, &mut buffer).await? {
+            ProtocolMessage::Response(re) => re,
+            _ => return Err(io::Error::other("a response was expected")),
+        };
+
+        assert_eq!(request_seq, response.request_seq);
+
+        let response = Response::try_from(&response).map_err(io::Error::other)?;
+
+        assert_eq!(Response::from_error(command, error), response);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn start_debugging_works() -> io::Result<()> {
+    let reactor = Reactor::<Forking>::new().bind("127.0.0.1:0").await?;
+    let socket = reactor.local_addr()?;
+
+    tokio::spawn(async move {
+        reactor.listen().await.ok();
+    });
+
+    let launch = Request::Launch {
+        arguments: LaunchArguments {
+            no_debug: false,
+            restart: None,
+            additional_attributes: Default::default(),
+        },
+    };
+
+    let (mut parent_reader, mut parent_writer) =
+        tokio::net::TcpStream::connect(socket).await?.into_split();
+    let mut parent_buffer = Vec::new();
+
+    let message = ProtocolMessage::from(launch.into_protocol(1)).into_adapter_message();
+
+    parent_writer.write_all(message.as_bytes()).await?;
+
+    let reverse = match read_message(&mut parent_reader, &mut parent_buffer).await? {
+        ProtocolMessage::Request(re) => re,
+        _ => return Err(io::Error::other("a reverse request was expected")),
+    };
+
+    let configuration = match ReverseRequest::try_from(&reverse)? {
+        ReverseRequest::StartDebugging {
+            configuration,
+            request: StartDebuggingRequestKind::Launch,
+        } => configuration,
+        _ => return Err(io::Error::other("wrong reverse request variant")),
+    };
+
+    // the child session connects back to the same listener, and is launched with the provided
+    // configuration
+    let (mut child_reader, mut child_writer) =
+        tokio::net::TcpStream::connect(socket).await?.into_split();
+
+    let launch = Request::Launch {
+        arguments: LaunchArguments::try_from(&configuration)?,
+    };
+    let message = ProtocolMessage::from(launch.into_protocol(1)).into_adapter_message();
+
+    child_writer.write_all(message.as_bytes()).await?;
+
+    let response = match read_message(&mut child_reader, &mut Vec::new()).await? {
+        ProtocolMessage::Response(re) => re,
+        _ => return Err(io::Error::other("a response was expected")),
+    };
+
+    assert_eq!(Response::Launch, Response::try_from(&response)?);
+
+    let message = ReverseResponse::StartDebugging.into_protocol(2, reverse.seq);
+    let message = ProtocolMessage::from(message).into_adapter_message();
+
+    parent_writer.write_all(message.as_bytes()).await?;
+
+    let response = match read_message(&mut parent_reader, &mut parent_buffer).await? {
+        ProtocolMessage::Response(re) => re,
+        _ => return Err(io::Error::other("a response was expected")),
+    };
+
+    assert_eq!(1, response.request_seq);
+    assert_eq!(Response::Launch, Response::try_from(&response)?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn reverse_request_disconnect_works() -> io::Result<()> {
+    let (tx, rx) = tokio::sync::oneshot::channel();
+
+    ORPHANED.lock().expect("orphaned lock poisoned").replace(tx);
+
+    let (client, server) = tokio::io::duplex(1024);
+    let (reader, writer) = tokio::io::split(server);
+
+    tokio::spawn(async move {
+        Reactor::<Orphan>::new().serve(reader, writer).await.ok();
+    });
+
+    let (mut client_reader, mut client_writer) = tokio::io::split(client);
+
+    let launch = Request::Launch {
+        arguments: LaunchArguments {
+            no_debug: false,
+            restart: None,
+            additional_attributes: Default::default(),
+        },
+    };
+    let message = ProtocolMessage::from(launch.into_protocol(1)).into_adapter_message();
+
+    client_writer.write_all(message.as_bytes()).await?;
+
+    assert!(matches!(
+        read_message(&mut client_reader, &mut Vec::new()).await?,
+        ProtocolMessage::Request(_)
+    ));
+
+    // the client goes away without replying to the reverse request
+    drop(client_reader);
+    drop(client_writer);
+
+    let response = tokio::time::timeout(Duration::from_secs(5), rx)
+        .await
+        .map_err(|_| io::Error::other("the reverse request is still pending"))?
+        .map_err(io::Error::other)?;
+
+    assert_eq!(
+        Some(io::ErrorKind::BrokenPipe),
+        response.err().map(|e| e.kind())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn capabilities_have_requests() {
+    // the capabilities are only sent if set, so every advertised one is checked
+    let capabilities = Value::from(Service::capabilities());
+    let capabilities = capabilities
+        .as_object()
+        .expect("capabilities must be an object");
+
+    for capability in capabilities.keys() {
+        let commands: &[&str] = match capability.as_str() {
+            "supportsConfigurationDoneRequest" => &["configurationDone"],
+            "supportsFunctionBreakpoints" => &["setFunctionBreakpoints"],
+            "exceptionBreakpointFilters" => &["setExceptionBreakpoints"],
+            "supportsStepBack" => &["stepBack", "reverseContinue"],
+            "supportsSetVariable" => &["setVariable"],
+            "supportsRestartFrame" => &["restartFrame"],
+            "supportsGotoTargetsRequest" => &["gotoTargets"],
+            "supportsStepInTargetsRequest" => &["stepInTargets"],
+            "supportsCompletionsRequest" => &["completions"],
+            "supportsModulesRequest" => &["modules"],
+            "supportsRestartRequest" => &["restart"],
+            "supportsExceptionInfoRequest" => &["exceptionInfo"],
+            "supportsLoadedSourcesRequest" => &["loadedSources"],
+            "supportsTerminateThreadsRequest" => &["terminateThreads"],
+            "supportsSetExpression" => &["setExpression"],
+            "supportsTerminateRequest" => &["terminate"],
+            "supportsDataBreakpoints" => &["dataBreakpointInfo", "setDataBreakpoints"],
+            "supportsReadMemoryRequest" => &["readMemory"],
+            "supportsWriteMemoryRequest" => &["writeMemory"],
+            "supportsDisassembleRequest" => &["disassemble"],
+            "supportsCancelRequest" => &["cancel"],
+            "supportsBreakpointLocationsRequest" => &["breakpointLocations"],
+            "supportsInstructionBreakpoints" => &["setInstructionBreakpoints"],
+
+            // these only extend the arguments or the responses of other requests
+            "supportsConditionalBreakpoints"
+            | "supportsHitConditionalBreakpoints"
+            | "supportsEvaluateForHovers"
+            | "completionTriggerCharacters"
+            | "additionalModuleColumns"
+            | "supportedChecksumAlgorithms"
+            | "supportsExceptionOptions"
+            | "supportsValueFormattingOptions"
+            | "supportTerminateDebuggee"
+            | "supportSuspendDebuggee"
+            | "supportsDelayedStackTraceLoading"
+            | "supportsLogPoints"
+            | "supportsClipboardContext"
+            | "supportsSteppingGranularity"
+            | "supportsExceptionFilterOptions"
+            | "supportsSingleThreadExecutionRequests" => &[],
+
+            _ => panic!("{capability} is not mapped to its requests"),
+        };
+
+        // a command without a variant falls back to `Custom`; a known command with missing
+        // arguments is rejected by its own variant instead
+        for command in commands {
+            let request = ProtocolRequest {
+                seq: 1,
+                command: command.to_string(),
+                arguments: None,
+            };
+
+            let request = Request::try_from(&request);
+
+            assert!(
+                !matches!(request, Ok(Request::Custom { .. })),
+                "{command} is advertised by {capability} but has no request variant"
+            );
+        }
+    }
+}
+
+fn initialize() -> Request {
+    Request::Initialize {
+        arguments: InitializeArguments {
+            client_id: None,
+            client_name: None,
+            adapter_id: "foo".into(),
+            locale: None,
+            lines_start_at_1: true,
+            column_start_at_1: true,
+            path_format: None,
+            supports_variable_type: true,
+            supports_variable_paging: false,
+            supports_run_in_terminal_request: false,
+            supports_memory_references: false,
+            supports_progress_reporting: false,
+            supports_invalidated_event: false,
+            supports_memory_event: false,
+            supports_args_can_be_interpreted_by_shell: false,
+            supports_start_debugging_request: false,
+        },
+    }
+}
+
+async fn read_message<R>(reader: &mut R, buffer: &mut Vec<u8>) -> io::Result<ProtocolMessage>
+where
+    R: AsyncRead + Unpin,
+{
+    loop {
+        match ProtocolMessage::try_from_bytes(&buffer) {
+            Ok((n, message)) => {
+                buffer.drain(..n);
+                return Ok(message);
+            }
+
+            Err(Error {
+                cause: Cause::UnexpectedEof | Cause::IsMandatory,
+                ..
+            }) => (),
+
+            Err(e) => return Err(e.into()),
+        }
+
+        if reader.read_buf(buffer).await? == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+    }
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn bind_unix_works() -> io::Result<()> {
+    let path = std::env::temp_dir().join(format!("dap-reactor-{}.sock", std::process::id()));
+
+    // leave a stale socket file behind
+    drop(std::os::unix::net::UnixListener::bind(&path)?);
+    assert!(path.exists());
+
+    let reactor = Reactor::<Service>::new().bind_unix(&path).await?;
+
+    assert_eq!(path, reactor.path());
+
+    let listener = tokio::spawn(async move {
+        reactor.listen().await.ok();
+    });
+
+    let stream = tokio::net::UnixStream::connect(&path).await?;
+    let (mut reader, mut writer) = stream.into_split();
+
+    let message = ProtocolMessage::from(initialize().into_protocol(1)).into_adapter_message();
+
+    writer.write_all(message.as_bytes()).await?;
+
+    let response = match read_message(&mut reader, &mut Vec::new()).await? {
+        ProtocolMessage::Response(re) => re,
+        _ => return Err(io::Error::other("a response was expected")),
+    };
+
+    assert!(matches!(
+        Response::try_from(&response)?,
+        Response::Initialize { .. }
+    ));
+
+    listener.abort();
+    listener.await.ok();
+
+    assert!(!path.exists());
+
+    Ok(())
+}
+
+async fn run_in_terminal(request: Request) -> io::Result<()> {
+    let (client, server) = tokio::io::duplex(1024);
+    let (reader, writer) = tokio::io::split(server);
+
+    tokio::spawn(async move {
+        Reactor::<Terminal>::new().serve(reader, writer).await.ok();
+    });
+
+    let (mut client_reader, mut client_writer) = tokio::io::split(client);
+    let mut buffer = Vec::new();
+
+    let command = request.clone().into_protocol(1).command;
+    let message = ProtocolMessage::from(request.into_protocol(1)).into_adapter_message();
+
+    client_writer.write_all(message.as_bytes()).await?;
+
+    loop {
+        match read_message(&mut client_reader, &mut buffer).await? {
+            ProtocolMessage::Request(re) => {
+                assert!(matches!(
+                    ReverseRequest::try_from(&re)?,
+                    ReverseRequest::RunInTerminal { .. }
+                ));
+
+                let response = ReverseResponse::RunInTerminal {
+                    process_id: Some(42),
+                    shell_process_id: None,
+                };
+
+                let message = ProtocolMessage::from(response.into_protocol(2, re.seq));
+                let message = message.into_adapter_message();
+
+                client_writer.write_all(message.as_bytes()).await?;
+            }
+
+            ProtocolMessage::Event(ev) => {
+                assert_eq!(Event::Exited { exit_code: 42 }, Event::try_from(&ev)?);
+
+                break;
+            }
+
+            ProtocolMessage::Response(re) => {
+                assert_eq!(command, re.command);
+            }
+        }
+    }
+
+    Ok(())
+}
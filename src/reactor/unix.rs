@@ -0,0 +1,131 @@
+use std::fs;
+use std::io;
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::os::unix::fs::FileTypeExt as _;
+use std::path::{Path, PathBuf};
+
+use tokio::net;
+
+use super::{session, Handler, Reactor};
+
+impl<B> Reactor<B>
+where
+    B: Handler,
+{
+    /// Bind a unix domain socket listener to the provided path.
+    ///
+    /// If the path points to a stale socket (i.e. no process is accepting connections on it), it
+    /// will be removed. The socket file is removed when the listener is dropped.
+    pub async fn bind_unix<P>(&mut self, path: P) -> io::Result<ReactorUnixListener<B>>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+
+        remove_stale_socket(path).await?;
+
+        let listener = net::UnixListener::bind(path)?;
+        let path = SocketPath(path.to_path_buf());
+
+        Ok(ReactorUnixListener {
+            capacity: self.capacity,
+            listener,
+            path,
+            provider: PhantomData,
+        })
+    }
+}
+
+pub struct ReactorUnixListener<B> {
+    capacity: usize,
+    listener: net::UnixListener,
+    path: SocketPath,
+    provider: PhantomData<B>,
+}
+
+impl<B> Deref for ReactorUnixListener<B> {
+    type Target = net::UnixListener;
+
+    fn deref(&self) -> &Self::Target {
+        &self.listener
+    }
+}
+
+impl<B> ReactorUnixListener<B>
+where
+    B: Handler,
+{
+    /// Path of the bound socket file.
+    pub fn path(&self) -> &Path {
+        &self.path.0
+    }
+
+    pub async fn listen(self) -> io::Result<()> {
+        let Self {
+            capacity,
+            listener,
+            path,
+            ..
+        } = self;
+
+        // `path` is kept alive for as long as the listener future, so the socket file is removed
+        // when the future is dropped
+        tracing::info!("listening on {}", path.0.display());
+
+        loop {
+            match listener.accept().await {
+                Ok((stream, _addr)) => {
+                    tracing::trace!("incoming unix connection");
+
+                    let (inbound, outbound) = stream.into_split();
+
+                    tokio::spawn(session::run::<B::Backend, _, _>(
+                        capacity, inbound, outbound,
+                    ));
+                }
+
+                Err(e) => tracing::error!("error accepting socket: {}", e),
+            }
+        }
+    }
+}
+
+/// Removes the socket file when dropped.
+struct SocketPath(PathBuf);
+
+impl Drop for SocketPath {
+    fn drop(&mut self) {
+        if let Err(e) = fs::remove_file(&self.0) {
+            tracing::warn!("error removing socket {}: {}", self.0.display(), e);
+        }
+    }
+}
+
+async fn remove_stale_socket(path: &Path) -> io::Result<()> {
+    let metadata = match fs::symlink_metadata(path) {
+        Ok(m) => m,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+    };
+
+    // never remove something that isn't a socket; let the bind fail instead
+    if !metadata.file_type().is_socket() {
+        return Ok(());
+    }
+
+    match net::UnixStream::connect(path).await {
+        Ok(_) => Err(io::Error::new(
+            io::ErrorKind::AddrInUse,
+            format!("socket {} is in use", path.display()),
+        )),
+
+        Err(e) if e.kind() == io::ErrorKind::ConnectionRefused => {
+            tracing::debug!("removing stale socket {}", path.display());
+
+            fs::remove_file(path)
+        }
+
+        Err(e) => Err(e),
+    }
+}
@@ -6,7 +6,7 @@ use crate::models::*;
 use crate::protocol::ProtocolRequest;
 use crate::utils;
 
-use serde_json::Value;
+use serde_json::{Map, Value};
 use std::collections::HashMap;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -26,12 +26,24 @@ pub enum Request {
     BreakpointLocations {
         arguments: Option<BreakpointLocationsArguments>,
     },
+    Cancel {
+        arguments: Option<CancelArguments>,
+    },
+    Completions {
+        arguments: CompletionsArguments,
+    },
     ConfigurationDone {
         arguments: Option<ConfigurationDoneArguments>,
     },
     Continue {
         arguments: ContinueArguments,
     },
+    DataBreakpointInfo {
+        arguments: DataBreakpointInfoArguments,
+    },
+    Disassemble {
+        arguments: DisassembleArguments,
+    },
     Evaluate {
         arguments: EvaluateArguments,
     },
@@ -41,6 +53,9 @@ pub enum Request {
     Goto {
         arguments: GotoArguments,
     },
+    GotoTargets {
+        arguments: GotoTargetsArguments,
+    },
     Initialize {
         arguments: InitializeArguments,
     },
@@ -50,9 +65,21 @@ pub enum Request {
     LoadedSources {
         arguments: Option<LoadedSourcesArguments>,
     },
+    Modules {
+        arguments: Option<ModulesArguments>,
+    },
     Next {
         arguments: Option<NextArguments>,
     },
+    Pause {
+        arguments: PauseArguments,
+    },
+    ReadMemory {
+        arguments: ReadMemoryArguments,
+    },
+    RestartFrame {
+        arguments: RestartFrameArguments,
+    },
     ReverseContinue {
         arguments: ReverseContinueArguments,
     },
@@ -62,17 +89,54 @@ pub enum Request {
     SetBreakpoints {
         arguments: SetBreakpointsArguments,
     },
+    SetDataBreakpoints {
+        arguments: SetDataBreakpointsArguments,
+    },
+    SetExceptionBreakpoints {
+        arguments: SetExceptionBreakpointsArguments,
+    },
+    SetExpression {
+        arguments: SetExpressionArguments,
+    },
+    SetFunctionBreakpoints {
+        arguments: SetFunctionBreakpointsArguments,
+    },
+    SetInstructionBreakpoints {
+        arguments: SetInstructionBreakpointsArguments,
+    },
+    SetVariable {
+        arguments: SetVariableArguments,
+    },
+    Source {
+        arguments: SourceArguments,
+    },
     StackTrace {
         arguments: StackTraceArguments,
     },
     StepBack {
         arguments: StepBackArguments,
     },
+    StepIn {
+        arguments: StepInArguments,
+    },
+    StepInTargets {
+        arguments: StepInTargetsArguments,
+    },
+    StepOut {
+        arguments: StepOutArguments,
+    },
+    TerminateThreads {
+        arguments: TerminateThreadsArguments,
+    },
     Threads,
     Variables {
         arguments: VariablesArguments,
     },
+    WriteMemory {
+        arguments: WriteMemoryArguments,
+    },
     Custom {
+        command: String,
         arguments: Option<Value>,
     },
 }
@@ -83,6 +147,12 @@ pub enum RunInTerminalKind {
     External,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StartDebuggingRequestKind {
+    Launch,
+    Attach,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ReverseRequest {
     RunInTerminal {
@@ -93,9 +163,24 @@ pub enum ReverseRequest {
         env: Option<HashMap<String, Option<String>>>,
         args_can_be_interpreted_by_shell: bool,
     },
+    /// Ask the client to start a child session, such as for a forked process.
+    ///
+    /// The configuration is sent as the arguments of the `launch` or `attach` request of the child
+    /// session. With the reactor, prefer `ReverseRequester::start_debugging` so the child session
+    /// can be linked to its parent.
+    StartDebugging {
+        configuration: Map<String, Value>,
+        request: StartDebuggingRequestKind,
+    },
 }
 
 impl Request {
+    /// Error to reply to a request with a command that isn't supported.
+    ///
+    /// Commands that aren't part of the protocol are parsed as [`Request::Custom`]. The reactor
+    /// replies with this error to the ones the backend doesn't reply to.
+    pub const UNKNOWN_COMMAND: Error = Error::new("request", Cause::ExpectsEnum);
+
     pub fn into_protocol(self, seq: u64) -> ProtocolRequest {
         let (command, arguments) = match self {
             Request::Attach { arguments } => {
@@ -133,6 +218,20 @@ impl Request {
                 (command, arguments)
             }
 
+            Request::Cancel { arguments } => {
+                let command = "cancel";
+                let arguments = arguments.map(Value::from);
+
+                (command, arguments)
+            }
+
+            Request::Completions { arguments } => {
+                let command = "completions";
+                let arguments = arguments.into();
+
+                (command, Some(arguments))
+            }
+
             Request::ConfigurationDone { arguments } => {
                 let command = "configurationDone";
                 let arguments = arguments.map(Value::from);
@@ -147,6 +246,20 @@ impl Request {
                 (command, Some(arguments))
             }
 
+            Request::DataBreakpointInfo { arguments } => {
+                let command = "dataBreakpointInfo";
+                let arguments = arguments.into();
+
+                (command, Some(arguments))
+            }
+
+            Request::Disassemble { arguments } => {
+                let command = "disassemble";
+                let arguments = arguments.into();
+
+                (command, Some(arguments))
+            }
+
             Request::Evaluate { arguments } => {
                 let command = "evaluate";
                 let arguments = arguments.into();
@@ -168,6 +281,13 @@ impl Request {
                 (command, Some(arguments))
             }
 
+            Request::GotoTargets { arguments } => {
+                let command = "gotoTargets";
+                let arguments = arguments.into();
+
+                (command, Some(arguments))
+            }
+
             Request::Initialize { arguments } => {
                 let command = "initialize";
                 let arguments = arguments.into();
@@ -189,6 +309,13 @@ impl Request {
                 (command, Some(arguments))
             }
 
+            Request::Modules { arguments } => {
+                let command = "modules";
+                let arguments = arguments.map(Value::from);
+
+                (command, arguments)
+            }
+
             Request::Next { arguments } => {
                 let command = "next";
                 let arguments = arguments.into();
@@ -196,6 +323,27 @@ impl Request {
                 (command, Some(arguments))
             }
 
+            Request::Pause { arguments } => {
+                let command = "pause";
+                let arguments = arguments.into();
+
+                (command, Some(arguments))
+            }
+
+            Request::ReadMemory { arguments } => {
+                let command = "readMemory";
+                let arguments = arguments.into();
+
+                (command, Some(arguments))
+            }
+
+            Request::RestartFrame { arguments } => {
+                let command = "restartFrame";
+                let arguments = arguments.into();
+
+                (command, Some(arguments))
+            }
+
             Request::ReverseContinue { arguments } => {
                 let command = "reverseContinue";
                 let arguments = arguments.into();
@@ -217,6 +365,55 @@ impl Request {
                 (command, Some(arguments))
             }
 
+            Request::SetDataBreakpoints { arguments } => {
+                let command = "setDataBreakpoints";
+                let arguments = arguments.into();
+
+                (command, Some(arguments))
+            }
+
+            Request::SetExceptionBreakpoints { arguments } => {
+                let command = "setExceptionBreakpoints";
+                let arguments = arguments.into();
+
+                (command, Some(arguments))
+            }
+
+            Request::SetExpression { arguments } => {
+                let command = "setExpression";
+                let arguments = arguments.into();
+
+                (command, Some(arguments))
+            }
+
+            Request::SetFunctionBreakpoints { arguments } => {
+                let command = "setFunctionBreakpoints";
+                let arguments = arguments.into();
+
+                (command, Some(arguments))
+            }
+
+            Request::SetInstructionBreakpoints { arguments } => {
+                let command = "setInstructionBreakpoints";
+                let arguments = arguments.into();
+
+                (command, Some(arguments))
+            }
+
+            Request::SetVariable { arguments } => {
+                let command = "setVariable";
+                let arguments = arguments.into();
+
+                (command, Some(arguments))
+            }
+
+            Request::Source { arguments } => {
+                let command = "source";
+                let arguments = arguments.into();
+
+                (command, Some(arguments))
+            }
+
             Request::StackTrace { arguments } => {
                 let command = "stackTrace";
                 let arguments = arguments.into();
@@ -231,6 +428,34 @@ impl Request {
                 (command, Some(arguments))
             }
 
+            Request::StepIn { arguments } => {
+                let command = "stepIn";
+                let arguments = arguments.into();
+
+                (command, Some(arguments))
+            }
+
+            Request::StepInTargets { arguments } => {
+                let command = "stepInTargets";
+                let arguments = arguments.into();
+
+                (command, Some(arguments))
+            }
+
+            Request::StepOut { arguments } => {
+                let command = "stepOut";
+                let arguments = arguments.into();
+
+                (command, Some(arguments))
+            }
+
+            Request::TerminateThreads { arguments } => {
+                let command = "terminateThreads";
+                let arguments = arguments.into();
+
+                (command, Some(arguments))
+            }
+
             Request::Threads => {
                 let command = "threads";
 
@@ -244,10 +469,19 @@ impl Request {
                 (command, Some(arguments))
             }
 
-            Request::Custom { arguments } => {
-                let command = "custom";
+            Request::WriteMemory { arguments } => {
+                let command = "writeMemory";
+                let arguments = arguments.into();
 
-                (command, arguments)
+                (command, Some(arguments))
+            }
+
+            Request::Custom { command, arguments } => {
+                return ProtocolRequest {
+                    seq,
+                    command,
+                    arguments,
+                };
             }
         };
 
@@ -301,6 +535,21 @@ impl TryFrom<&ProtocolRequest> for Request {
                 Ok(Self::BreakpointLocations { arguments })
             }
 
+            "cancel" => {
+                let arguments = arguments.map(CancelArguments::try_from).transpose()?;
+
+                Ok(Self::Cancel { arguments })
+            }
+
+            "completions" => {
+                let arguments =
+                    arguments.ok_or_else(|| Error::new("arguments", Cause::IsMandatory))?;
+
+                let arguments = CompletionsArguments::try_from(arguments)?;
+
+                Ok(Self::Completions { arguments })
+            }
+
             "configurationDone" => {
                 let arguments = arguments.map(ConfigurationDoneArguments::from);
 
@@ -316,6 +565,24 @@ impl TryFrom<&ProtocolRequest> for Request {
                 Ok(Self::Continue { arguments })
             }
 
+            "dataBreakpointInfo" => {
+                let arguments =
+                    arguments.ok_or_else(|| Error::new("arguments", Cause::IsMandatory))?;
+
+                let arguments = DataBreakpointInfoArguments::try_from(arguments)?;
+
+                Ok(Self::DataBreakpointInfo { arguments })
+            }
+
+            "disassemble" => {
+                let arguments =
+                    arguments.ok_or_else(|| Error::new("arguments", Cause::IsMandatory))?;
+
+                let arguments = DisassembleArguments::try_from(arguments)?;
+
+                Ok(Self::Disassemble { arguments })
+            }
+
             "evaluate" => {
                 let arguments =
                     arguments.ok_or_else(|| Error::new("arguments", Cause::IsMandatory))?;
@@ -343,6 +610,15 @@ impl TryFrom<&ProtocolRequest> for Request {
                 Ok(Self::Goto { arguments })
             }
 
+            "gotoTargets" => {
+                let arguments =
+                    arguments.ok_or_else(|| Error::new("arguments", Cause::IsMandatory))?;
+
+                let arguments = GotoTargetsArguments::try_from(arguments)?;
+
+                Ok(Self::GotoTargets { arguments })
+            }
+
             "initialize" => {
                 let arguments =
                     arguments.ok_or_else(|| Error::new("arguments", Cause::IsMandatory))?;
@@ -367,12 +643,45 @@ impl TryFrom<&ProtocolRequest> for Request {
                 Ok(Self::LoadedSources { arguments })
             }
 
+            "modules" => {
+                let arguments = arguments.map(ModulesArguments::try_from).transpose()?;
+
+                Ok(Self::Modules { arguments })
+            }
+
             "next" => {
                 let arguments = arguments.map(NextArguments::try_from).transpose()?;
 
                 Ok(Self::Next { arguments })
             }
 
+            "pause" => {
+                let arguments =
+                    arguments.ok_or_else(|| Error::new("arguments", Cause::IsMandatory))?;
+
+                let arguments = PauseArguments::try_from(arguments)?;
+
+                Ok(Self::Pause { arguments })
+            }
+
+            "readMemory" => {
+                let arguments =
+                    arguments.ok_or_else(|| Error::new("arguments", Cause::IsMandatory))?;
+
+                let arguments = ReadMemoryArguments::try_from(arguments)?;
+
+                Ok(Self::ReadMemory { arguments })
+            }
+
+            "restartFrame" => {
+                let arguments =
+                    arguments.ok_or_else(|| Error::new("arguments", Cause::IsMandatory))?;
+
+                let arguments = RestartFrameArguments::try_from(arguments)?;
+
+                Ok(Self::RestartFrame { arguments })
+            }
+
             "reverseContinue" => {
                 let arguments =
                     arguments.ok_or_else(|| Error::new("arguments", Cause::IsMandatory))?;
@@ -400,6 +709,69 @@ impl TryFrom<&ProtocolRequest> for Request {
                 Ok(Self::SetBreakpoints { arguments })
             }
 
+            "setDataBreakpoints" => {
+                let arguments =
+                    arguments.ok_or_else(|| Error::new("arguments", Cause::IsMandatory))?;
+
+                let arguments = SetDataBreakpointsArguments::try_from(arguments)?;
+
+                Ok(Self::SetDataBreakpoints { arguments })
+            }
+
+            "setExceptionBreakpoints" => {
+                let arguments =
+                    arguments.ok_or_else(|| Error::new("arguments", Cause::IsMandatory))?;
+
+                let arguments = SetExceptionBreakpointsArguments::try_from(arguments)?;
+
+                Ok(Self::SetExceptionBreakpoints { arguments })
+            }
+
+            "setExpression" => {
+                let arguments =
+                    arguments.ok_or_else(|| Error::new("arguments", Cause::IsMandatory))?;
+
+                let arguments = SetExpressionArguments::try_from(arguments)?;
+
+                Ok(Self::SetExpression { arguments })
+            }
+
+            "setFunctionBreakpoints" => {
+                let arguments =
+                    arguments.ok_or_else(|| Error::new("arguments", Cause::IsMandatory))?;
+
+                let arguments = SetFunctionBreakpointsArguments::try_from(arguments)?;
+
+                Ok(Self::SetFunctionBreakpoints { arguments })
+            }
+
+            "setInstructionBreakpoints" => {
+                let arguments =
+                    arguments.ok_or_else(|| Error::new("arguments", Cause::IsMandatory))?;
+
+                let arguments = SetInstructionBreakpointsArguments::try_from(arguments)?;
+
+                Ok(Self::SetInstructionBreakpoints { arguments })
+            }
+
+            "setVariable" => {
+                let arguments =
+                    arguments.ok_or_else(|| Error::new("arguments", Cause::IsMandatory))?;
+
+                let arguments = SetVariableArguments::try_from(arguments)?;
+
+                Ok(Self::SetVariable { arguments })
+            }
+
+            "source" => {
+                let arguments =
+                    arguments.ok_or_else(|| Error::new("arguments", Cause::IsMandatory))?;
+
+                let arguments = SourceArguments::try_from(arguments)?;
+
+                Ok(Self::Source { arguments })
+            }
+
             "stackTrace" => {
                 let arguments =
                     arguments.ok_or_else(|| Error::new("arguments", Cause::IsMandatory))?;
@@ -418,6 +790,42 @@ impl TryFrom<&ProtocolRequest> for Request {
                 Ok(Self::StepBack { arguments })
             }
 
+            "stepIn" => {
+                let arguments =
+                    arguments.ok_or_else(|| Error::new("arguments", Cause::IsMandatory))?;
+
+                let arguments = StepInArguments::try_from(arguments)?;
+
+                Ok(Self::StepIn { arguments })
+            }
+
+            "stepInTargets" => {
+                let arguments =
+                    arguments.ok_or_else(|| Error::new("arguments", Cause::IsMandatory))?;
+
+                let arguments = StepInTargetsArguments::try_from(arguments)?;
+
+                Ok(Self::StepInTargets { arguments })
+            }
+
+            "stepOut" => {
+                let arguments =
+                    arguments.ok_or_else(|| Error::new("arguments", Cause::IsMandatory))?;
+
+                let arguments = StepOutArguments::try_from(arguments)?;
+
+                Ok(Self::StepOut { arguments })
+            }
+
+            "terminateThreads" => {
+                let arguments =
+                    arguments.ok_or_else(|| Error::new("arguments", Cause::IsMandatory))?;
+
+                let arguments = TerminateThreadsArguments::try_from(arguments)?;
+
+                Ok(Self::TerminateThreads { arguments })
+            }
+
             "threads" => Ok(Self::Threads),
 
             "variables" => {
@@ -429,13 +837,21 @@ impl TryFrom<&ProtocolRequest> for Request {
                 Ok(Self::Variables { arguments })
             }
 
-            "custom" => {
-                let arguments = rq.arguments.as_ref().cloned();
+            "writeMemory" => {
+                let arguments =
+                    arguments.ok_or_else(|| Error::new("arguments", Cause::IsMandatory))?;
 
-                Ok(Self::Custom { arguments })
+                let arguments = WriteMemoryArguments::try_from(arguments)?;
+
+                Ok(Self::WriteMemory { arguments })
             }
 
-            _ => Err(Error::new("request", Cause::ExpectsEnum)),
+            command => {
+                let command = command.to_string();
+                let arguments = rq.arguments.as_ref().cloned();
+
+                Ok(Self::Custom { command, arguments })
+            }
         }
     }
 }
@@ -461,6 +877,27 @@ impl TryFrom<&str> for RunInTerminalKind {
     }
 }
 
+impl From<StartDebuggingRequestKind> for String {
+    fn from(k: StartDebuggingRequestKind) -> Self {
+        match k {
+            StartDebuggingRequestKind::Launch => "launch".to_string(),
+            StartDebuggingRequestKind::Attach => "attach".to_string(),
+        }
+    }
+}
+
+impl TryFrom<&str> for StartDebuggingRequestKind {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s {
+            "launch" => Ok(Self::Launch),
+            "attach" => Ok(Self::Attach),
+            _ => Err(Error::new("request", Cause::ExpectsEnum)),
+        }
+    }
+}
+
 impl ReverseRequest {
     pub fn into_protocol(self, seq: u64) -> ProtocolRequest {
         let (command, arguments) = match self {
@@ -494,6 +931,20 @@ impl ReverseRequest {
 
                 (command, Some(arguments))
             }
+
+            ReverseRequest::StartDebugging {
+                configuration,
+                request,
+            } => {
+                let command = "startDebugging";
+
+                let configuration = utils::attribute("configuration", configuration);
+                let request = utils::attribute_string("request", request);
+
+                let arguments = utils::finalize_object(configuration.chain(request));
+
+                (command, Some(arguments))
+            }
         };
 
         ProtocolRequest {
@@ -535,6 +986,18 @@ impl TryFrom<&ProtocolRequest> for ReverseRequest {
                 })
             }
 
+            "startDebugging" => {
+                let map = arguments.ok_or(Error::new("arguments", Cause::IsMandatory))?;
+
+                let configuration = utils::get_map(map, "configuration")?.clone();
+                let request = StartDebuggingRequestKind::try_from(utils::get_str(map, "request")?)?;
+
+                Ok(Self::StartDebugging {
+                    configuration,
+                    request,
+                })
+            }
+
             _ => Err(Error::new("reverseRequest", Cause::ExpectsEnum)),
         }
     }
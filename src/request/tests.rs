@@ -13,11 +13,16 @@ fn encode_requests() {
                 "command": "attach",
                 "arguments": {
                     "__restart": "some-test",
+                    "pid": 42,
                 }
             }),
             decoded: Request::Attach {
                 arguments: AttachArguments {
                     restart: Some(Value::String("some-test".into())),
+                    additional_attributes: json!({ "pid": 42 })
+                        .as_object()
+                        .cloned()
+                        .unwrap_or_default(),
                 },
             },
         },
@@ -28,12 +33,55 @@ fn encode_requests() {
                 "arguments": {
                     "noDebug": true,
                     "__restart": "some-test",
+                    "program": "foo",
                 }
             }),
             decoded: Request::Restart {
                 arguments: Some(RestartArguments::Launch(LaunchArguments {
                     no_debug: true,
                     restart: Some(Value::String("some-test".into())),
+                    additional_attributes: json!({ "program": "foo" })
+                        .as_object()
+                        .cloned()
+                        .unwrap_or_default(),
+                })),
+            },
+        },
+        RequestTestCase {
+            seq: 13,
+            encoded: json!({
+                "command": "restart",
+                "arguments": {
+                    "noDebug": false,
+                    "program": "foo",
+                }
+            }),
+            decoded: Request::Restart {
+                arguments: Some(RestartArguments::Launch(LaunchArguments {
+                    no_debug: false,
+                    restart: None,
+                    additional_attributes: json!({ "program": "foo" })
+                        .as_object()
+                        .cloned()
+                        .unwrap_or_default(),
+                })),
+            },
+        },
+        RequestTestCase {
+            seq: 13,
+            encoded: json!({
+                "command": "restart",
+                "arguments": {
+                    "port": 8080,
+                }
+            }),
+            decoded: Request::Restart {
+                arguments: Some(RestartArguments::Attach(AttachArguments {
+                    restart: None,
+                    additional_attributes: json!({ "port": 8080 })
+                        .as_object()
+                        .cloned()
+                        .unwrap_or_default(),
                 })),
             },
         },
@@ -98,6 +146,29 @@ fn encode_requests() {
                 }),
             },
         },
+        RequestTestCase {
+            seq: 29,
+            encoded: json!({
+                "command": "cancel",
+                "arguments": {
+                    "requestId": 17,
+                    "progressId": "progress",
+                },
+            }),
+            decoded: Request::Cancel {
+                arguments: Some(CancelArguments {
+                    request_id: Some(17),
+                    progress_id: Some("progress".into()),
+                }),
+            },
+        },
+        RequestTestCase {
+            seq: 29,
+            encoded: json!({
+                "command": "cancel",
+            }),
+            decoded: Request::Cancel { arguments: None },
+        },
         RequestTestCase {
             seq: 17,
             encoded: json!({
@@ -184,7 +255,7 @@ fn encode_requests() {
                     "adapterID": "adapterID",
                     "locale": "locale",
                     "linesStartAt1": true,
-                    "columnStartAt1": true,
+                    "columnsStartAt1": true,
                     "pathFormat": "uri",
                     "supportsVariableType": true,
                     "supportsVariablePaging": true,
@@ -194,6 +265,7 @@ fn encode_requests() {
                     "supportsInvalidatedEvent": true,
                     "supportsMemoryEvent": true,
                     "supportsArgsCanBeInterpretedByShell": true,
+                    "supportsStartDebuggingRequest": true,
                 }
             }),
             decoded: Request::Initialize {
@@ -213,6 +285,7 @@ fn encode_requests() {
                     supports_invalidated_event: true,
                     supports_memory_event: true,
                     supports_args_can_be_interpreted_by_shell: true,
+                    supports_start_debugging_request: true,
                 },
             },
         },
@@ -222,13 +295,30 @@ fn encode_requests() {
                 "command": "launch",
                 "arguments": {
                     "noDebug": true,
-                    "__restart": 0
+                    "__restart": 0,
+                    "program": "foo",
+                    "args": ["bar", "baz"],
+                    "cwd": "/tmp",
+                    "env": {
+                        "FOO": "bar"
+                    }
                 }
             }),
             decoded: Request::Launch {
                 arguments: LaunchArguments {
                     no_debug: true,
                     restart: Some(Value::from(0)),
+                    additional_attributes: json!({
+                        "program": "foo",
+                        "args": ["bar", "baz"],
+                        "cwd": "/tmp",
+                        "env": {
+                            "FOO": "bar"
+                        }
+                    })
+                    .as_object()
+                    .cloned()
+                    .unwrap_or_default(),
                 },
             },
         },
@@ -344,9 +434,33 @@ fn encode_requests() {
                 "arguments": "foo"
             }),
             decoded: Request::Custom {
+                command: "custom".into(),
                 arguments: Some("foo".into()),
             },
         },
+        RequestTestCase {
+            seq: 13,
+            encoded: json!({
+                "command": "rustAnalyzer/runFlycheck",
+                "arguments": {
+                    "textDocument": "foo.rs"
+                }
+            }),
+            decoded: Request::Custom {
+                command: "rustAnalyzer/runFlycheck".into(),
+                arguments: Some(json!({ "textDocument": "foo.rs" })),
+            },
+        },
+        RequestTestCase {
+            seq: 13,
+            encoded: json!({
+                "command": "toggleSkipFileStatus",
+            }),
+            decoded: Request::Custom {
+                command: "toggleSkipFileStatus".into(),
+                arguments: None,
+            },
+        },
         RequestTestCase {
             seq: 13,
             encoded: json!({
@@ -426,11 +540,609 @@ fn encode_requests() {
                 },
             },
         },
+        RequestTestCase {
+            seq: 30,
+            encoded: json!({
+                "command": "pause",
+                "arguments": {
+                    "threadId": 12,
+                }
+            }),
+            decoded: Request::Pause {
+                arguments: PauseArguments { thread_id: 12 },
+            },
+        },
+        RequestTestCase {
+            seq: 31,
+            encoded: json!({
+                "command": "stepIn",
+                "arguments": {
+                    "threadId": 12,
+                    "singleThread": true,
+                    "targetId": 3,
+                    "granularity": "instruction",
+                }
+            }),
+            decoded: Request::StepIn {
+                arguments: StepInArguments {
+                    thread_id: 12,
+                    single_thread: true,
+                    target_id: Some(3),
+                    granularity: Some(SteppingGranularity::Instruction),
+                },
+            },
+        },
+        RequestTestCase {
+            seq: 32,
+            encoded: json!({
+                "command": "stepIn",
+                "arguments": {
+                    "threadId": 12,
+                }
+            }),
+            decoded: Request::StepIn {
+                arguments: StepInArguments {
+                    thread_id: 12,
+                    single_thread: false,
+                    target_id: None,
+                    granularity: None,
+                },
+            },
+        },
+        RequestTestCase {
+            seq: 33,
+            encoded: json!({
+                "command": "stepOut",
+                "arguments": {
+                    "threadId": 12,
+                    "singleThread": true,
+                    "granularity": "line",
+                }
+            }),
+            decoded: Request::StepOut {
+                arguments: StepOutArguments {
+                    thread_id: 12,
+                    single_thread: true,
+                    granularity: Some(SteppingGranularity::Line),
+                },
+            },
+        },
+        RequestTestCase {
+            seq: 34,
+            encoded: json!({
+                "command": "setFunctionBreakpoints",
+                "arguments": {
+                    "breakpoints": [{
+                        "name": "main",
+                        "condition": "argc > 1",
+                        "hitCondition": "3",
+                    }, {
+                        "name": "exit",
+                    }],
+                }
+            }),
+            decoded: Request::SetFunctionBreakpoints {
+                arguments: SetFunctionBreakpointsArguments {
+                    breakpoints: vec![
+                        FunctionBreakpoint {
+                            name: "main".into(),
+                            condition: Some("argc > 1".into()),
+                            hit_condition: Some("3".into()),
+                        },
+                        FunctionBreakpoint {
+                            name: "exit".into(),
+                            condition: None,
+                            hit_condition: None,
+                        },
+                    ],
+                },
+            },
+        },
+        RequestTestCase {
+            seq: 35,
+            encoded: json!({
+                "command": "setExceptionBreakpoints",
+                "arguments": {
+                    "filters": ["uncaught"],
+                    "filterOptions": [{
+                        "filterId": "raised",
+                        "condition": "e.code == 2",
+                    }],
+                    "exceptionOptions": [{
+                        "path": [{
+                            "negate": true,
+                            "names": ["ValueError", "KeyError"],
+                        }],
+                        "breakMode": "userUnhandled",
+                    }, {
+                        "breakMode": "always",
+                    }],
+                }
+            }),
+            decoded: Request::SetExceptionBreakpoints {
+                arguments: SetExceptionBreakpointsArguments {
+                    filters: vec!["uncaught".into()],
+                    filter_options: vec![ExceptionFilterOptions {
+                        filter_id: "raised".into(),
+                        condition: Some("e.code == 2".into()),
+                    }],
+                    exception_options: vec![
+                        ExceptionOptions {
+                            path: vec![ExceptionPathSegment {
+                                negate: true,
+                                names: vec!["ValueError".into(), "KeyError".into()],
+                            }],
+                            break_mode: ExceptionBreakMode::UserUnhandled,
+                        },
+                        ExceptionOptions {
+                            path: vec![],
+                            break_mode: ExceptionBreakMode::Always,
+                        },
+                    ],
+                },
+            },
+        },
+        RequestTestCase {
+            seq: 36,
+            encoded: json!({
+                "command": "setExceptionBreakpoints",
+                "arguments": {
+                    "filters": [],
+                }
+            }),
+            decoded: Request::SetExceptionBreakpoints {
+                arguments: SetExceptionBreakpointsArguments {
+                    filters: vec![],
+                    filter_options: vec![],
+                    exception_options: vec![],
+                },
+            },
+        },
+        RequestTestCase {
+            seq: 37,
+            encoded: json!({
+                "command": "source",
+                "arguments": {
+                    "source": {
+                        "name": "decompiled",
+                        "sourceReference": 7,
+                    },
+                    "sourceReference": 7,
+                }
+            }),
+            decoded: Request::Source {
+                arguments: SourceArguments {
+                    source: Some(Source {
+                        name: Some("decompiled".into()),
+                        source_reference: Some(SourceReference::Reference(7)),
+                        presentation_hint: None,
+                        origin: None,
+                        sources: vec![],
+                        adapter_data: None,
+                        checksums: vec![],
+                    }),
+                    source_reference: 7,
+                },
+            },
+        },
+        RequestTestCase {
+            seq: 38,
+            encoded: json!({
+                "command": "modules",
+                "arguments": {
+                    "startModule": 10,
+                    "moduleCount": 20,
+                }
+            }),
+            decoded: Request::Modules {
+                arguments: Some(ModulesArguments {
+                    start_module: Some(10),
+                    module_count: Some(20),
+                }),
+            },
+        },
+        RequestTestCase {
+            seq: 39,
+            encoded: json!({
+                "command": "modules",
+            }),
+            decoded: Request::Modules { arguments: None },
+        },
+        RequestTestCase {
+            seq: 40,
+            encoded: json!({
+                "command": "completions",
+                "arguments": {
+                    "frameId": 3,
+                    "text": "foo.ba",
+                    "column": 7,
+                    "line": 1,
+                }
+            }),
+            decoded: Request::Completions {
+                arguments: CompletionsArguments {
+                    frame_id: Some(3),
+                    text: "foo.ba".into(),
+                    column: 7,
+                    line: Some(1),
+                },
+            },
+        },
+        RequestTestCase {
+            seq: 41,
+            encoded: json!({
+                "command": "setVariable",
+                "arguments": {
+                    "variablesReference": 5,
+                    "name": "x",
+                    "value": "42",
+                    "format": {
+                        "hex": true,
+                    },
+                }
+            }),
+            decoded: Request::SetVariable {
+                arguments: SetVariableArguments {
+                    variables_reference: 5,
+                    name: "x".into(),
+                    value: "42".into(),
+                    format: Some(ValueFormat { hex: true }),
+                },
+            },
+        },
+        RequestTestCase {
+            seq: 42,
+            encoded: json!({
+                "command": "setExpression",
+                "arguments": {
+                    "expression": "foo.bar",
+                    "value": "\"baz\"",
+                    "frameId": 2,
+                }
+            }),
+            decoded: Request::SetExpression {
+                arguments: SetExpressionArguments {
+                    expression: "foo.bar".into(),
+                    value: "\"baz\"".into(),
+                    frame_id: Some(2),
+                    format: None,
+                },
+            },
+        },
+        RequestTestCase {
+            seq: 43,
+            encoded: json!({
+                "command": "readMemory",
+                "arguments": {
+                    "memoryReference": "0x1000",
+                    "offset": -4,
+                    "count": 8,
+                }
+            }),
+            decoded: Request::ReadMemory {
+                arguments: ReadMemoryArguments {
+                    memory_reference: "0x1000".into(),
+                    offset: Some(-4),
+                    count: 8,
+                },
+            },
+        },
+        RequestTestCase {
+            seq: 44,
+            encoded: json!({
+                "command": "writeMemory",
+                "arguments": {
+                    "memoryReference": "0x1000",
+                    "allowPartial": true,
+                    "data": "3q2+7w==",
+                }
+            }),
+            decoded: Request::WriteMemory {
+                arguments: WriteMemoryArguments {
+                    memory_reference: "0x1000".into(),
+                    offset: None,
+                    allow_partial: true,
+                    data: vec![0xde, 0xad, 0xbe, 0xef],
+                },
+            },
+        },
+        RequestTestCase {
+            seq: 46,
+            encoded: json!({
+                "command": "disassemble",
+                "arguments": {
+                    "memoryReference": "0x1000",
+                    "offset": 16,
+                    "instructionOffset": -2,
+                    "instructionCount": 4,
+                    "resolveSymbols": true,
+                }
+            }),
+            decoded: Request::Disassemble {
+                arguments: DisassembleArguments {
+                    memory_reference: "0x1000".into(),
+                    offset: Some(16),
+                    instruction_offset: Some(-2),
+                    instruction_count: 4,
+                    resolve_symbols: true,
+                },
+            },
+        },
+        RequestTestCase {
+            seq: 47,
+            encoded: json!({
+                "command": "dataBreakpointInfo",
+                "arguments": {
+                    "variablesReference": 7,
+                    "name": "counter",
+                }
+            }),
+            decoded: Request::DataBreakpointInfo {
+                arguments: DataBreakpointInfoArguments {
+                    variables_reference: Some(7),
+                    name: "counter".into(),
+                    frame_id: None,
+                },
+            },
+        },
+        RequestTestCase {
+            seq: 48,
+            encoded: json!({
+                "command": "setDataBreakpoints",
+                "arguments": {
+                    "breakpoints": [{
+                        "dataId": "counter@7",
+                        "accessType": "readWrite",
+                        "condition": "counter > 3",
+                        "hitCondition": "2",
+                    }, {
+                        "dataId": "total@7",
+                    }]
+                }
+            }),
+            decoded: Request::SetDataBreakpoints {
+                arguments: SetDataBreakpointsArguments {
+                    breakpoints: vec![
+                        DataBreakpoint {
+                            data_id: "counter@7".into(),
+                            access_type: Some(DataBreakpointAccessType::ReadWrite),
+                            condition: Some("counter > 3".into()),
+                            hit_condition: Some("2".into()),
+                        },
+                        DataBreakpoint {
+                            data_id: "total@7".into(),
+                            access_type: None,
+                            condition: None,
+                            hit_condition: None,
+                        },
+                    ],
+                },
+            },
+        },
+        RequestTestCase {
+            seq: 49,
+            encoded: json!({
+                "command": "setInstructionBreakpoints",
+                "arguments": {
+                    "breakpoints": [{
+                        "instructionReference": "0x1008",
+                        "offset": -4,
+                        "condition": "rax == 0",
+                        "hitCondition": "3",
+                    }, {
+                        "instructionReference": "0x2000",
+                    }]
+                }
+            }),
+            decoded: Request::SetInstructionBreakpoints {
+                arguments: SetInstructionBreakpointsArguments {
+                    breakpoints: vec![
+                        InstructionBreakpoint {
+                            instruction_reference: "0x1008".into(),
+                            offset: Some(-4),
+                            condition: Some("rax == 0".into()),
+                            hit_condition: Some("3".into()),
+                        },
+                        InstructionBreakpoint {
+                            instruction_reference: "0x2000".into(),
+                            offset: None,
+                            condition: None,
+                            hit_condition: None,
+                        },
+                    ],
+                },
+            },
+        },
+        RequestTestCase {
+            seq: 50,
+            encoded: json!({
+                "command": "gotoTargets",
+                "arguments": {
+                    "source": {
+                        "path": "main.c",
+                    },
+                    "line": 12,
+                    "column": 5,
+                }
+            }),
+            decoded: Request::GotoTargets {
+                arguments: GotoTargetsArguments {
+                    source: Source {
+                        name: None,
+                        source_reference: Some(SourceReference::Path("main.c".into())),
+                        presentation_hint: None,
+                        origin: None,
+                        sources: Vec::new(),
+                        adapter_data: None,
+                        checksums: Vec::new(),
+                    },
+                    line: 12,
+                    column: Some(5),
+                },
+            },
+        },
+        RequestTestCase {
+            seq: 51,
+            encoded: json!({
+                "command": "stepInTargets",
+                "arguments": {
+                    "frameId": 3,
+                }
+            }),
+            decoded: Request::StepInTargets {
+                arguments: StepInTargetsArguments { frame_id: 3 },
+            },
+        },
+        RequestTestCase {
+            seq: 52,
+            encoded: json!({
+                "command": "restartFrame",
+                "arguments": {
+                    "frameId": 3,
+                }
+            }),
+            decoded: Request::RestartFrame {
+                arguments: RestartFrameArguments { frame_id: 3 },
+            },
+        },
+        RequestTestCase {
+            seq: 53,
+            encoded: json!({
+                "command": "terminateThreads",
+                "arguments": {
+                    "threadIds": [1, 4],
+                }
+            }),
+            decoded: Request::TerminateThreads {
+                arguments: TerminateThreadsArguments {
+                    thread_ids: vec![1, 4],
+                },
+            },
+        },
+        RequestTestCase {
+            seq: 54,
+            encoded: json!({
+                "command": "terminateThreads",
+                "arguments": {}
+            }),
+            decoded: Request::TerminateThreads {
+                arguments: TerminateThreadsArguments {
+                    thread_ids: Vec::new(),
+                },
+            },
+        },
     ];
 
     cases.into_iter().for_each(|case| case.run());
 }
 
+#[test]
+fn completions_column_offset() {
+    let arguments = CompletionsArguments {
+        frame_id: None,
+        text: "foo.ba".into(),
+        column: 7,
+        line: None,
+    };
+
+    assert_eq!(6, arguments.column_offset(true));
+    assert_eq!(7, arguments.column_offset(false));
+
+    let mut item = CompletionItem {
+        label: "bar".into(),
+        text: None,
+        sort_text: None,
+        detail: None,
+        r#type: None,
+        start: None,
+        length: None,
+        selection_start: None,
+        selection_length: None,
+    };
+
+    item.set_start_offset(4, true);
+
+    assert_eq!(Some(5), item.start);
+    assert_eq!(Some(4), item.start_offset(true));
+    assert_eq!(Some(5), item.start_offset(false));
+}
+
+#[test]
+fn initialize_columns_start_at_1() {
+    // initialize request sent by vscode
+    let request = ProtocolRequest {
+        seq: 1,
+        command: "initialize".into(),
+        arguments: Some(json!({
+            "clientID": "vscode",
+            "clientName": "Visual Studio Code",
+            "adapterID": "lldb",
+            "pathFormat": "path",
+            "linesStartAt1": true,
+            "columnsStartAt1": true,
+            "supportsVariableType": true,
+            "supportsVariablePaging": true,
+            "supportsRunInTerminalRequest": true,
+            "locale": "en",
+            "supportsProgressReporting": true,
+            "supportsInvalidatedEvent": true,
+            "supportsMemoryReferences": true,
+            "supportsArgsCanBeInterpretedByShell": true,
+            "supportsMemoryEvent": true,
+            "supportsStartDebuggingRequest": true,
+        })),
+    };
+
+    let arguments = match Request::try_from(&request) {
+        Ok(Request::Initialize { arguments }) => arguments,
+        r => panic!("unexpected request: {:?}", r),
+    };
+
+    assert!(arguments.lines_start_at_1);
+    assert!(arguments.column_start_at_1);
+
+    let completions = CompletionsArguments {
+        frame_id: None,
+        text: "foo.ba".into(),
+        column: 7,
+        line: None,
+    };
+
+    assert_eq!(6, completions.column_offset(arguments.column_start_at_1));
+
+    // both default to `true` when omitted
+    let request = ProtocolRequest {
+        seq: 1,
+        command: "initialize".into(),
+        arguments: Some(json!({ "adapterID": "lldb" })),
+    };
+
+    let arguments = match Request::try_from(&request) {
+        Ok(Request::Initialize { arguments }) => arguments,
+        r => panic!("unexpected request: {:?}", r),
+    };
+
+    assert!(arguments.lines_start_at_1);
+    assert!(arguments.column_start_at_1);
+}
+
+#[test]
+fn write_memory_invalid_data() {
+    let request = ProtocolRequest {
+        seq: 45,
+        command: "writeMemory".into(),
+        arguments: Some(json!({
+            "memoryReference": "0x1000",
+            "data": "not base64!",
+        })),
+    };
+
+    assert_eq!(
+        Err(Error::new("data", Cause::IsInvalid)),
+        Request::try_from(&request)
+    );
+}
+
 struct RequestTestCase {
     seq: u64,
     encoded: Value,
@@ -451,7 +1163,11 @@ impl RequestTestCase {
         let encoded =
             ProtocolMessage::try_from(&encoded).expect("failed to parse encoded protocol message");
 
-        let protocol = decoded.into_protocol(seq);
+        let protocol = decoded.clone().into_protocol(seq);
+
+        let request = Request::try_from(&protocol).expect("failed to decode request");
+        assert_eq!(decoded, request);
+
         let protocol = ProtocolMessage::from(protocol);
 
         assert_eq!(encoded, protocol);
@@ -7,6 +7,7 @@ use crate::error::{Cause, Error};
 use crate::models::*;
 use crate::protocol::ProtocolResponse;
 use crate::protocol::ProtocolResponseError;
+use crate::request::Request;
 use crate::utils;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -22,10 +23,20 @@ pub enum Response {
     BreakpointLocations {
         body: Option<BreakpointLocationsResponse>,
     },
+    Cancel,
+    Completions {
+        body: CompletionsResponse,
+    },
     ConfigurationDone,
     Continue {
         body: ContinueResponse,
     },
+    DataBreakpointInfo {
+        body: DataBreakpointInfoResponse,
+    },
+    Disassemble {
+        body: Option<DisassembleResponse>,
+    },
     Evaluate {
         body: EvaluateResponse,
     },
@@ -33,6 +44,9 @@ pub enum Response {
         body: ExceptionInfoResponse,
     },
     Goto,
+    GotoTargets {
+        body: GotoTargetsResponse,
+    },
     Initialize {
         body: InitializeResponse,
     },
@@ -40,7 +54,15 @@ pub enum Response {
     LoadedSources {
         body: LoadedSourcesResponse,
     },
+    Modules {
+        body: ModulesResponse,
+    },
     Next,
+    Pause,
+    ReadMemory {
+        body: Option<ReadMemoryResponse>,
+    },
+    RestartFrame,
     ReverseContinue,
     Scopes {
         body: ScopesResponse,
@@ -48,17 +70,48 @@ pub enum Response {
     SetBreakpoints {
         body: SetBreakpointsResponse,
     },
+    SetDataBreakpoints {
+        body: SetDataBreakpointsResponse,
+    },
+    SetExceptionBreakpoints {
+        body: Option<SetExceptionBreakpointsResponse>,
+    },
+    SetExpression {
+        body: SetExpressionResponse,
+    },
+    SetFunctionBreakpoints {
+        body: SetFunctionBreakpointsResponse,
+    },
+    SetInstructionBreakpoints {
+        body: SetInstructionBreakpointsResponse,
+    },
+    SetVariable {
+        body: SetVariableResponse,
+    },
+    Source {
+        body: SourceResponse,
+    },
     StackTrace {
         body: StackTraceResponse,
     },
     StepBack,
+    StepIn,
+    StepInTargets {
+        body: StepInTargetsResponse,
+    },
+    StepOut,
+    TerminateThreads,
     Threads {
         body: ThreadsResponse,
     },
     Variables {
         body: VariablesResponse,
     },
+    WriteMemory {
+        body: Option<WriteMemoryResponse>,
+    },
     Custom {
+        command: String,
         body: Option<Value>,
     },
 }
@@ -73,9 +126,30 @@ pub enum ReverseResponse {
         process_id: Option<u32>,
         shell_process_id: Option<u32>,
     },
+    StartDebugging,
 }
 
 impl Response {
+    /// Error response to a request that couldn't be parsed.
+    pub fn from_error<C>(command: C, error: Error) -> Self
+    where
+        C: Into<String>,
+    {
+        let message = if error == Request::UNKNOWN_COMMAND {
+            "unknown command"
+        } else {
+            "invalid request"
+        };
+
+        Self::Error {
+            command: command.into(),
+            error: ProtocolResponseError {
+                message: Some(message.to_string()),
+                body: Some(error.into()),
+            },
+        }
+    }
+
     pub fn into_protocol(self, seq: u64, request_seq: u64) -> ProtocolResponse {
         match self {
             Response::Error { command, error } => ProtocolResponse {
@@ -114,6 +188,18 @@ impl Response {
                 command: "breakpointLocations".to_string(),
                 result: Ok(Some(body.into())),
             },
+            Response::Cancel => ProtocolResponse {
+                seq,
+                request_seq,
+                command: "cancel".to_string(),
+                result: Ok(None),
+            },
+            Response::Completions { body } => ProtocolResponse {
+                seq,
+                request_seq,
+                command: "completions".to_string(),
+                result: Ok(Some(body.into())),
+            },
             Response::ConfigurationDone => ProtocolResponse {
                 seq,
                 request_seq,
@@ -126,6 +212,18 @@ impl Response {
                 command: "continue".to_string(),
                 result: Ok(Some(body.into())),
             },
+            Response::DataBreakpointInfo { body } => ProtocolResponse {
+                seq,
+                request_seq,
+                command: "dataBreakpointInfo".to_string(),
+                result: Ok(Some(body.into())),
+            },
+            Response::Disassemble { body } => ProtocolResponse {
+                seq,
+                request_seq,
+                command: "disassemble".to_string(),
+                result: Ok(body.map(Value::from)),
+            },
             Response::Evaluate { body } => ProtocolResponse {
                 seq,
                 request_seq,
@@ -144,6 +242,12 @@ impl Response {
                 command: "goto".to_string(),
                 result: Ok(None),
             },
+            Response::GotoTargets { body } => ProtocolResponse {
+                seq,
+                request_seq,
+                command: "gotoTargets".to_string(),
+                result: Ok(Some(body.into())),
+            },
             Response::Initialize { body } => ProtocolResponse {
                 seq,
                 request_seq,
@@ -162,12 +266,36 @@ impl Response {
                 command: "loadedSources".to_string(),
                 result: Ok(Some(body.into())),
             },
+            Response::Modules { body } => ProtocolResponse {
+                seq,
+                request_seq,
+                command: "modules".to_string(),
+                result: Ok(Some(body.into())),
+            },
             Response::Next => ProtocolResponse {
                 seq,
                 request_seq,
                 command: "next".to_string(),
                 result: Ok(None),
             },
+            Response::Pause => ProtocolResponse {
+                seq,
+                request_seq,
+                command: "pause".to_string(),
+                result: Ok(None),
+            },
+            Response::ReadMemory { body } => ProtocolResponse {
+                seq,
+                request_seq,
+                command: "readMemory".to_string(),
+                result: Ok(body.map(Value::from)),
+            },
+            Response::RestartFrame => ProtocolResponse {
+                seq,
+                request_seq,
+                command: "restartFrame".to_string(),
+                result: Ok(None),
+            },
             Response::ReverseContinue => ProtocolResponse {
                 seq,
                 request_seq,
@@ -186,6 +314,48 @@ impl Response {
                 command: "setBreakpoints".to_string(),
                 result: Ok(Some(body.into())),
             },
+            Response::SetDataBreakpoints { body } => ProtocolResponse {
+                seq,
+                request_seq,
+                command: "setDataBreakpoints".to_string(),
+                result: Ok(Some(body.into())),
+            },
+            Response::SetExceptionBreakpoints { body } => ProtocolResponse {
+                seq,
+                request_seq,
+                command: "setExceptionBreakpoints".to_string(),
+                result: Ok(body.map(Value::from)),
+            },
+            Response::SetExpression { body } => ProtocolResponse {
+                seq,
+                request_seq,
+                command: "setExpression".to_string(),
+                result: Ok(Some(body.into())),
+            },
+            Response::SetFunctionBreakpoints { body } => ProtocolResponse {
+                seq,
+                request_seq,
+                command: "setFunctionBreakpoints".to_string(),
+                result: Ok(Some(body.into())),
+            },
+            Response::SetInstructionBreakpoints { body } => ProtocolResponse {
+                seq,
+                request_seq,
+                command: "setInstructionBreakpoints".to_string(),
+                result: Ok(Some(body.into())),
+            },
+            Response::SetVariable { body } => ProtocolResponse {
+                seq,
+                request_seq,
+                command: "setVariable".to_string(),
+                result: Ok(Some(body.into())),
+            },
+            Response::Source { body } => ProtocolResponse {
+                seq,
+                request_seq,
+                command: "source".to_string(),
+                result: Ok(Some(body.into())),
+            },
             Response::StackTrace { body } => ProtocolResponse {
                 seq,
                 request_seq,
@@ -198,6 +368,30 @@ impl Response {
                 command: "stepBack".to_string(),
                 result: Ok(None),
             },
+            Response::StepIn => ProtocolResponse {
+                seq,
+                request_seq,
+                command: "stepIn".to_string(),
+                result: Ok(None),
+            },
+            Response::StepInTargets { body } => ProtocolResponse {
+                seq,
+                request_seq,
+                command: "stepInTargets".to_string(),
+                result: Ok(Some(body.into())),
+            },
+            Response::StepOut => ProtocolResponse {
+                seq,
+                request_seq,
+                command: "stepOut".to_string(),
+                result: Ok(None),
+            },
+            Response::TerminateThreads => ProtocolResponse {
+                seq,
+                request_seq,
+                command: "terminateThreads".to_string(),
+                result: Ok(None),
+            },
             Response::Threads { body } => ProtocolResponse {
                 seq,
                 request_seq,
@@ -210,10 +404,16 @@ impl Response {
                 command: "variables".to_string(),
                 result: Ok(Some(body.into())),
             },
-            Response::Custom { body } => ProtocolResponse {
+            Response::WriteMemory { body } => ProtocolResponse {
+                seq,
+                request_seq,
+                command: "writeMemory".to_string(),
+                result: Ok(body.map(Value::from)),
+            },
+            Response::Custom { command, body } => ProtocolResponse {
                 seq,
                 request_seq,
-                command: "custom".to_string(),
+                command,
                 result: Ok(body),
             },
         }
@@ -234,11 +434,14 @@ impl TryFrom<&ProtocolResponse> for Response {
             }
         };
 
-        let result = res
-            .as_ref()
-            .ok_or(Error::new("result", Cause::IsMandatory))?
-            .as_object()
-            .ok_or(Error::new("result", Cause::MustBeObject))?;
+        // responses without a body are valid for most commands, so the body is required only
+        // when it is parsed
+        let result = || {
+            res.as_ref()
+                .ok_or(Error::new("result", Cause::IsMandatory))?
+                .as_object()
+                .ok_or(Error::new("result", Cause::MustBeObject))
+        };
 
         match re.command.as_str() {
             "attach" => Ok(Self::Attach),
@@ -246,48 +449,111 @@ impl TryFrom<&ProtocolResponse> for Response {
             "disconnect" => Ok(Self::Disconnect),
             "terminate" => Ok(Self::Terminate),
             "breakpointLocations" => Ok(Self::BreakpointLocations {
-                body: Some(BreakpointLocationsResponse::try_from(result)?),
+                body: Some(BreakpointLocationsResponse::try_from(result()?)?),
+            }),
+            "cancel" => Ok(Self::Cancel),
+            "completions" => Ok(Self::Completions {
+                body: CompletionsResponse::try_from(result()?)?,
             }),
             "configurationDone" => Ok(Self::ConfigurationDone),
             "continue" => Ok(Self::Continue {
-                body: ContinueResponse::try_from(result)?,
+                body: ContinueResponse::try_from(result()?)?,
+            }),
+            "dataBreakpointInfo" => Ok(Self::DataBreakpointInfo {
+                body: DataBreakpointInfoResponse::try_from(result()?)?,
+            }),
+            "disassemble" => Ok(Self::Disassemble {
+                body: res
+                    .as_ref()
+                    .map(|_| result().and_then(DisassembleResponse::try_from))
+                    .transpose()?,
             }),
             "evaluate" => Ok(Self::Evaluate {
-                body: EvaluateResponse::try_from(result)?,
+                body: EvaluateResponse::try_from(result()?)?,
             }),
             "exceptionInfo" => Ok(Self::ExceptionInfo {
-                body: ExceptionInfoResponse::try_from(result)?,
+                body: ExceptionInfoResponse::try_from(result()?)?,
             }),
             "goto" => Ok(Self::Goto),
+            "gotoTargets" => Ok(Self::GotoTargets {
+                body: GotoTargetsResponse::try_from(result()?)?,
+            }),
             "initialize" => Ok(Self::Initialize {
-                body: InitializeResponse::try_from(result)?,
+                body: InitializeResponse::try_from(result()?)?,
             }),
             "launch" => Ok(Self::Launch),
             "loadedSources" => Ok(Self::LoadedSources {
-                body: LoadedSourcesResponse::try_from(result)?,
+                body: LoadedSourcesResponse::try_from(result()?)?,
+            }),
+            "modules" => Ok(Self::Modules {
+                body: ModulesResponse::try_from(result()?)?,
             }),
             "next" => Ok(Self::Next),
+            "pause" => Ok(Self::Pause),
+            "readMemory" => Ok(Self::ReadMemory {
+                body: res
+                    .as_ref()
+                    .map(|_| result().and_then(ReadMemoryResponse::try_from))
+                    .transpose()?,
+            }),
+            "restartFrame" => Ok(Self::RestartFrame),
             "reverseContinue" => Ok(Self::ReverseContinue),
             "scopes" => Ok(Self::Scopes {
-                body: ScopesResponse::try_from(result)?,
+                body: ScopesResponse::try_from(result()?)?,
             }),
             "setBreakpoints" => Ok(Self::SetBreakpoints {
-                body: SetBreakpointsResponse::try_from(result)?,
+                body: SetBreakpointsResponse::try_from(result()?)?,
+            }),
+            "setDataBreakpoints" => Ok(Self::SetDataBreakpoints {
+                body: SetDataBreakpointsResponse::try_from(result()?)?,
+            }),
+            "setExceptionBreakpoints" => Ok(Self::SetExceptionBreakpoints {
+                body: res
+                    .as_ref()
+                    .map(|_| result().and_then(SetExceptionBreakpointsResponse::try_from))
+                    .transpose()?,
+            }),
+            "setExpression" => Ok(Self::SetExpression {
+                body: SetExpressionResponse::try_from(result()?)?,
+            }),
+            "setFunctionBreakpoints" => Ok(Self::SetFunctionBreakpoints {
+                body: SetFunctionBreakpointsResponse::try_from(result()?)?,
+            }),
+            "setInstructionBreakpoints" => Ok(Self::SetInstructionBreakpoints {
+                body: SetInstructionBreakpointsResponse::try_from(result()?)?,
+            }),
+            "setVariable" => Ok(Self::SetVariable {
+                body: SetVariableResponse::try_from(result()?)?,
+            }),
+            "source" => Ok(Self::Source {
+                body: SourceResponse::try_from(result()?)?,
             }),
             "stackTrace" => Ok(Self::StackTrace {
-                body: StackTraceResponse::try_from(result)?,
+                body: StackTraceResponse::try_from(result()?)?,
             }),
             "stepBack" => Ok(Self::StepBack),
+            "stepIn" => Ok(Self::StepIn),
+            "stepInTargets" => Ok(Self::StepInTargets {
+                body: StepInTargetsResponse::try_from(result()?)?,
+            }),
+            "stepOut" => Ok(Self::StepOut),
+            "terminateThreads" => Ok(Self::TerminateThreads),
             "threads" => Ok(Self::Threads {
-                body: ThreadsResponse::try_from(result)?,
+                body: ThreadsResponse::try_from(result()?)?,
             }),
             "variables" => Ok(Self::Variables {
-                body: VariablesResponse::try_from(result)?,
+                body: VariablesResponse::try_from(result()?)?,
+            }),
+            "writeMemory" => Ok(Self::WriteMemory {
+                body: res
+                    .as_ref()
+                    .map(|_| result().and_then(WriteMemoryResponse::try_from))
+                    .transpose()?,
             }),
-            "custom" => Ok(Self::Custom {
+            command => Ok(Self::Custom {
+                command: command.to_string(),
                 body: res.as_ref().cloned(),
             }),
-            _ => Err(Error::new("response", Cause::ExpectsEnum)),
         }
     }
 }
@@ -318,6 +584,12 @@ impl ReverseResponse {
                     result: Ok(Some(result)),
                 }
             }
+            ReverseResponse::StartDebugging => ProtocolResponse {
+                seq,
+                request_seq,
+                command: "startDebugging".to_string(),
+                result: Ok(None),
+            },
         }
     }
 }
@@ -353,6 +625,8 @@ impl TryFrom<&ProtocolResponse> for ReverseResponse {
                 })
             }
 
+            "startDebugging" => Ok(Self::StartDebugging),
+
             _ => Err(Error::new("reverseResponse", Cause::ExpectsEnum)),
         }
     }
@@ -108,6 +108,15 @@ fn encode_responses() {
                 }),
             },
         },
+        ResponseTestCase {
+            seq: 1515,
+            request_seq: 29,
+            encoded: json!({
+                "command": "cancel",
+                "success": true,
+            }),
+            decoded: Response::Cancel,
+        },
         ResponseTestCase {
             seq: 1505,
             request_seq: 17,
@@ -473,9 +482,22 @@ fn encode_responses() {
                 "body": "foo"
             }),
             decoded: Response::Custom {
+                command: "custom".into(),
                 body: Some("foo".into()),
             },
         },
+        ResponseTestCase {
+            seq: 1512,
+            request_seq: 24,
+            encoded: json!({
+                "command": "toggleSkipFileStatus",
+                "success": true,
+            }),
+            decoded: Response::Custom {
+                command: "toggleSkipFileStatus".into(),
+                body: None,
+            },
+        },
         ResponseTestCase {
             seq: 1514,
             request_seq: 26,
@@ -674,6 +696,605 @@ fn encode_responses() {
                 },
             },
         },
+        ResponseTestCase {
+            seq: 1516,
+            request_seq: 30,
+            encoded: json!({
+                "command": "pause",
+                "success": true,
+            }),
+            decoded: Response::Pause,
+        },
+        ResponseTestCase {
+            seq: 1517,
+            request_seq: 31,
+            encoded: json!({
+                "command": "stepIn",
+                "success": true,
+            }),
+            decoded: Response::StepIn,
+        },
+        ResponseTestCase {
+            seq: 1518,
+            request_seq: 33,
+            encoded: json!({
+                "command": "stepOut",
+                "success": true,
+            }),
+            decoded: Response::StepOut,
+        },
+        ResponseTestCase {
+            seq: 1519,
+            request_seq: 34,
+            encoded: json!({
+                "command": "setFunctionBreakpoints",
+                "success": true,
+                "body": {
+                    "breakpoints": [{
+                        "id": 1,
+                        "verified": true,
+                        "line": 10,
+                    }, {
+                        "verified": false,
+                        "message": "function not found",
+                    }]
+                }
+            }),
+            decoded: Response::SetFunctionBreakpoints {
+                body: SetFunctionBreakpointsResponse {
+                    breakpoints: vec![
+                        Breakpoint {
+                            id: Some(1),
+                            verified: true,
+                            message: None,
+                            source: None,
+                            line: Some(10),
+                            column: None,
+                            end_line: None,
+                            end_column: None,
+                            instruction_reference: None,
+                            offset: None,
+                        },
+                        Breakpoint {
+                            id: None,
+                            verified: false,
+                            message: Some("function not found".into()),
+                            source: None,
+                            line: None,
+                            column: None,
+                            end_line: None,
+                            end_column: None,
+                            instruction_reference: None,
+                            offset: None,
+                        },
+                    ],
+                },
+            },
+        },
+        ResponseTestCase {
+            seq: 1520,
+            request_seq: 35,
+            encoded: json!({
+                "command": "setExceptionBreakpoints",
+                "success": true,
+                "body": {
+                    "breakpoints": [{
+                        "verified": true,
+                    }]
+                }
+            }),
+            decoded: Response::SetExceptionBreakpoints {
+                body: Some(SetExceptionBreakpointsResponse {
+                    breakpoints: vec![Breakpoint {
+                        id: None,
+                        verified: true,
+                        message: None,
+                        source: None,
+                        line: None,
+                        column: None,
+                        end_line: None,
+                        end_column: None,
+                        instruction_reference: None,
+                        offset: None,
+                    }],
+                }),
+            },
+        },
+        ResponseTestCase {
+            seq: 1521,
+            request_seq: 36,
+            encoded: json!({
+                "command": "setExceptionBreakpoints",
+                "success": true,
+            }),
+            decoded: Response::SetExceptionBreakpoints { body: None },
+        },
+        ResponseTestCase {
+            seq: 1522,
+            request_seq: 37,
+            encoded: json!({
+                "command": "source",
+                "success": true,
+                "body": {
+                    "content": "fn main() {}",
+                    "mimeType": "text/x-rust",
+                }
+            }),
+            decoded: Response::Source {
+                body: SourceResponse {
+                    content: "fn main() {}".into(),
+                    mime_type: Some("text/x-rust".into()),
+                },
+            },
+        },
+        ResponseTestCase {
+            seq: 1523,
+            request_seq: 38,
+            encoded: json!({
+                "command": "modules",
+                "success": true,
+                "body": {
+                    "modules": [{
+                        "id": 1,
+                        "name": "libfoo.so",
+                        "isUserCode": true,
+                        "version": "1.2.3",
+                    }],
+                    "totalModules": 40,
+                }
+            }),
+            decoded: Response::Modules {
+                body: ModulesResponse {
+                    modules: vec![Module {
+                        id: StackFrameModuleId::Number(1),
+                        name: "libfoo.so".into(),
+                        path: None,
+                        is_optimized: false,
+                        is_user_code: true,
+                        version: Some("1.2.3".into()),
+                        symbol_status: None,
+                        symbol_file_path: None,
+                        date_time_stamp: None,
+                        address_range: None,
+                        additional_attributes: Default::default(),
+                    }],
+                    total_modules: Some(40),
+                },
+            },
+        },
+        ResponseTestCase {
+            seq: 1524,
+            request_seq: 40,
+            encoded: json!({
+                "command": "completions",
+                "success": true,
+                "body": {
+                    "targets": [{
+                        "label": "bar",
+                        "text": "bar()",
+                        "sortText": "a",
+                        "detail": "fn bar()",
+                        "type": "method",
+                        "start": 5,
+                        "length": 2,
+                        "selectionStart": 4,
+                        "selectionLength": 0,
+                    }, {
+                        "label": "baz",
+                        "type": "customcolor",
+                    }]
+                }
+            }),
+            decoded: Response::Completions {
+                body: CompletionsResponse {
+                    targets: vec![
+                        CompletionItem {
+                            label: "bar".into(),
+                            text: Some("bar()".into()),
+                            sort_text: Some("a".into()),
+                            detail: Some("fn bar()".into()),
+                            r#type: Some(CompletionItemType::Method),
+                            start: Some(5),
+                            length: Some(2),
+                            selection_start: Some(4),
+                            selection_length: Some(0),
+                        },
+                        CompletionItem {
+                            label: "baz".into(),
+                            text: None,
+                            sort_text: None,
+                            detail: None,
+                            r#type: Some(CompletionItemType::CustomColor),
+                            start: None,
+                            length: None,
+                            selection_start: None,
+                            selection_length: None,
+                        },
+                    ],
+                },
+            },
+        },
+        ResponseTestCase {
+            seq: 1525,
+            request_seq: 41,
+            encoded: json!({
+                "command": "setVariable",
+                "success": true,
+                "body": {
+                    "value": "0x2a",
+                    "type": "i32",
+                    "variablesReference": 0,
+                }
+            }),
+            decoded: Response::SetVariable {
+                body: SetVariableResponse {
+                    value: "0x2a".into(),
+                    r#type: Some("i32".into()),
+                    variables_reference: Some(0),
+                    named_variables: None,
+                    indexed_variables: None,
+                },
+            },
+        },
+        ResponseTestCase {
+            seq: 1526,
+            request_seq: 42,
+            encoded: json!({
+                "command": "setExpression",
+                "success": true,
+                "body": {
+                    "value": "\"baz\"",
+                    "type": "String",
+                    "presentationHint": {
+                        "kind": "property",
+                        "attributes": ["readOnly"],
+                    },
+                    "variablesReference": 8,
+                    "namedVariables": 3,
+                    "indexedVariables": 1,
+                }
+            }),
+            decoded: Response::SetExpression {
+                body: SetExpressionResponse {
+                    value: "\"baz\"".into(),
+                    r#type: Some("String".into()),
+                    presentation_hint: Some(VariablePresentationHint {
+                        kind: Some(VariablePresentationHintKind::Property),
+                        attributes: vec![VariablePresentationHintAttribute::ReadOnly],
+                        visibility: None,
+                        lazy: false,
+                    }),
+                    variables_reference: Some(8),
+                    named_variables: Some(3),
+                    indexed_variables: Some(1),
+                },
+            },
+        },
+        ResponseTestCase {
+            seq: 1527,
+            request_seq: 43,
+            encoded: json!({
+                "command": "readMemory",
+                "success": true,
+                "body": {
+                    "address": "0xffc",
+                    "unreadableBytes": 4,
+                    "data": "3q2+7w==",
+                }
+            }),
+            decoded: Response::ReadMemory {
+                body: Some(ReadMemoryResponse {
+                    address: "0xffc".into(),
+                    unreadable_bytes: Some(4),
+                    data: vec![0xde, 0xad, 0xbe, 0xef],
+                }),
+            },
+        },
+        ResponseTestCase {
+            seq: 1528,
+            request_seq: 44,
+            encoded: json!({
+                "command": "writeMemory",
+                "success": true,
+                "body": {
+                    "offset": 0,
+                    "bytesWritten": 4,
+                }
+            }),
+            decoded: Response::WriteMemory {
+                body: Some(WriteMemoryResponse {
+                    offset: Some(0),
+                    bytes_written: Some(4),
+                }),
+            },
+        },
+        ResponseTestCase {
+            seq: 1529,
+            request_seq: 44,
+            encoded: json!({
+                "command": "writeMemory",
+                "success": true,
+            }),
+            decoded: Response::WriteMemory { body: None },
+        },
+        ResponseTestCase {
+            seq: 1530,
+            request_seq: 46,
+            encoded: json!({
+                "command": "disassemble",
+                "success": true,
+                "body": {
+                    "instructions": [{
+                        "address": "0x1008",
+                        "instructionBytes": "48 89 e5",
+                        "instruction": "mov rbp, rsp",
+                        "symbol": "main",
+                        "location": {
+                            "path": "main.c",
+                        },
+                        "line": 3,
+                        "column": 1,
+                        "endLine": 4,
+                        "endColumn": 2,
+                    }, {
+                        "address": "0x100b",
+                        "instruction": "nop",
+                    }]
+                }
+            }),
+            decoded: Response::Disassemble {
+                body: Some(DisassembleResponse {
+                    instructions: vec![
+                        DisassembledInstruction {
+                            address: "0x1008".into(),
+                            instruction_bytes: Some("48 89 e5".into()),
+                            instruction: "mov rbp, rsp".into(),
+                            symbol: Some("main".into()),
+                            location: Some(Source {
+                                name: None,
+                                source_reference: Some(SourceReference::Path("main.c".into())),
+                                presentation_hint: None,
+                                origin: None,
+                                sources: Vec::new(),
+                                adapter_data: None,
+                                checksums: Vec::new(),
+                            }),
+                            line: Some(3),
+                            column: Some(1),
+                            end_line: Some(4),
+                            end_column: Some(2),
+                        },
+                        DisassembledInstruction {
+                            address: "0x100b".into(),
+                            instruction_bytes: None,
+                            instruction: "nop".into(),
+                            symbol: None,
+                            location: None,
+                            line: None,
+                            column: None,
+                            end_line: None,
+                            end_column: None,
+                        },
+                    ],
+                }),
+            },
+        },
+        ResponseTestCase {
+            seq: 1531,
+            request_seq: 47,
+            encoded: json!({
+                "command": "dataBreakpointInfo",
+                "success": true,
+                "body": {
+                    "dataId": "counter@7",
+                    "description": "counter",
+                    "accessTypes": ["read", "write", "readWrite"],
+                    "canPersist": true,
+                }
+            }),
+            decoded: Response::DataBreakpointInfo {
+                body: DataBreakpointInfoResponse {
+                    data_id: Some("counter@7".into()),
+                    description: "counter".into(),
+                    access_types: vec![
+                        DataBreakpointAccessType::Read,
+                        DataBreakpointAccessType::Write,
+                        DataBreakpointAccessType::ReadWrite,
+                    ],
+                    can_persist: true,
+                },
+            },
+        },
+        ResponseTestCase {
+            seq: 1532,
+            request_seq: 47,
+            encoded: json!({
+                "command": "dataBreakpointInfo",
+                "success": true,
+                "body": {
+                    "dataId": null,
+                    "description": "counter is a register",
+                }
+            }),
+            decoded: Response::DataBreakpointInfo {
+                body: DataBreakpointInfoResponse {
+                    data_id: None,
+                    description: "counter is a register".into(),
+                    access_types: Vec::new(),
+                    can_persist: false,
+                },
+            },
+        },
+        ResponseTestCase {
+            seq: 1533,
+            request_seq: 48,
+            encoded: json!({
+                "command": "setDataBreakpoints",
+                "success": true,
+                "body": {
+                    "breakpoints": [{
+                        "id": 2,
+                        "verified": true,
+                    }]
+                }
+            }),
+            decoded: Response::SetDataBreakpoints {
+                body: SetDataBreakpointsResponse {
+                    breakpoints: vec![Breakpoint {
+                        id: Some(2),
+                        verified: true,
+                        message: None,
+                        source: None,
+                        line: None,
+                        column: None,
+                        end_line: None,
+                        end_column: None,
+                        instruction_reference: None,
+                        offset: None,
+                    }],
+                },
+            },
+        },
+        ResponseTestCase {
+            seq: 1534,
+            request_seq: 49,
+            encoded: json!({
+                "command": "setInstructionBreakpoints",
+                "success": true,
+                "body": {
+                    "breakpoints": [{
+                        "id": 3,
+                        "verified": true,
+                        "instructionReference": "0x1008",
+                        "offset": -4,
+                    }]
+                }
+            }),
+            decoded: Response::SetInstructionBreakpoints {
+                body: SetInstructionBreakpointsResponse {
+                    breakpoints: vec![Breakpoint {
+                        id: Some(3),
+                        verified: true,
+                        message: None,
+                        source: None,
+                        line: None,
+                        column: None,
+                        end_line: None,
+                        end_column: None,
+                        instruction_reference: Some("0x1008".into()),
+                        offset: Some(-4),
+                    }],
+                },
+            },
+        },
+        ResponseTestCase {
+            seq: 1535,
+            request_seq: 50,
+            encoded: json!({
+                "command": "gotoTargets",
+                "success": true,
+                "body": {
+                    "targets": [{
+                        "id": 1,
+                        "label": "x = 1",
+                        "line": 12,
+                        "column": 5,
+                        "endLine": 12,
+                        "endColumn": 10,
+                        "instructionPointerReference": "0x1008",
+                    }, {
+                        "id": 2,
+                        "label": "y = 2",
+                        "line": 13,
+                    }]
+                }
+            }),
+            decoded: Response::GotoTargets {
+                body: GotoTargetsResponse {
+                    targets: vec![
+                        GotoTarget {
+                            id: 1,
+                            label: "x = 1".into(),
+                            line: 12,
+                            column: Some(5),
+                            end_line: Some(12),
+                            end_column: Some(10),
+                            instruction_pointer_reference: Some("0x1008".into()),
+                        },
+                        GotoTarget {
+                            id: 2,
+                            label: "y = 2".into(),
+                            line: 13,
+                            column: None,
+                            end_line: None,
+                            end_column: None,
+                            instruction_pointer_reference: None,
+                        },
+                    ],
+                },
+            },
+        },
+        ResponseTestCase {
+            seq: 1536,
+            request_seq: 51,
+            encoded: json!({
+                "command": "stepInTargets",
+                "success": true,
+                "body": {
+                    "targets": [{
+                        "id": 4,
+                        "label": "foo()",
+                        "line": 12,
+                        "column": 9,
+                        "endLine": 12,
+                        "endColumn": 14,
+                    }, {
+                        "id": 5,
+                        "label": "bar()",
+                    }]
+                }
+            }),
+            decoded: Response::StepInTargets {
+                body: StepInTargetsResponse {
+                    targets: vec![
+                        StepInTarget {
+                            id: 4,
+                            label: "foo()".into(),
+                            line: Some(12),
+                            column: Some(9),
+                            end_line: Some(12),
+                            end_column: Some(14),
+                        },
+                        StepInTarget {
+                            id: 5,
+                            label: "bar()".into(),
+                            line: None,
+                            column: None,
+                            end_line: None,
+                            end_column: None,
+                        },
+                    ],
+                },
+            },
+        },
+        ResponseTestCase {
+            seq: 1537,
+            request_seq: 52,
+            encoded: json!({
+                "command": "restartFrame",
+                "success": true,
+            }),
+            decoded: Response::RestartFrame,
+        },
+        ResponseTestCase {
+            seq: 1538,
+            request_seq: 53,
+            encoded: json!({
+                "command": "terminateThreads",
+                "success": true,
+            }),
+            decoded: Response::TerminateThreads,
+        },
     ];
 
     cases.into_iter().for_each(|case| case.run());
@@ -702,7 +1323,11 @@ impl ResponseTestCase {
         let encoded =
             ProtocolMessage::try_from(&encoded).expect("failed to parse encoded protocol message");
 
-        let protocol = decoded.into_protocol(seq, request_seq);
+        let protocol = decoded.clone().into_protocol(seq, request_seq);
+
+        let response = Response::try_from(&protocol).expect("failed to decode response");
+        assert_eq!(decoded, response);
+
         let protocol = ProtocolMessage::from(protocol);
 
         assert_eq!(encoded, protocol);
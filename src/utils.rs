@@ -1,6 +1,8 @@
 use core::iter;
 use std::collections::HashMap;
 
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine as _;
 use serde_json::{Map, Value};
 
 use crate::error::*;
@@ -57,6 +59,17 @@ pub fn get_optional(map: &Map<String, Value>, attribute: &'static str) -> Option
     map.get(attribute).filter(|v| !v.is_null()).cloned()
 }
 
+/// Collect the attributes of the map that aren't part of the provided list.
+pub fn get_additional_attributes(
+    map: &Map<String, Value>,
+    attributes: &[&'static str],
+) -> Map<String, Value> {
+    map.iter()
+        .filter(|(k, _)| !attributes.contains(&k.as_str()))
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect()
+}
+
 pub fn get_map<'a>(
     map: &'a Map<String, Value>,
     attribute: &'static str,
@@ -279,6 +292,38 @@ pub fn get_string(map: &Map<String, Value>, attribute: &'static str) -> Result<S
     get_string_optional(map, attribute)?.ok_or_else(|| Error::new(attribute, Cause::IsMandatory))
 }
 
+pub fn get_string_or_null(
+    map: &Map<String, Value>,
+    attribute: &'static str,
+) -> Result<Option<String>, Error> {
+    match map.get(attribute) {
+        Some(Value::Null) => Ok(None),
+        Some(Value::String(s)) => Ok(Some(s.to_string())),
+        Some(_) => Err(Error::new(attribute, Cause::MustBeString)),
+        None => Err(Error::new(attribute, Cause::IsMandatory)),
+    }
+}
+
+pub fn get_base64_optional(
+    map: &Map<String, Value>,
+    attribute: &'static str,
+) -> Result<Vec<u8>, Error> {
+    get_str_optional(map, attribute)?
+        .map(|s| {
+            BASE64
+                .decode(s)
+                .map_err(|_| Error::new(attribute, Cause::IsInvalid))
+        })
+        .transpose()
+        .map(|b| b.unwrap_or_default())
+}
+
+pub fn get_base64(map: &Map<String, Value>, attribute: &'static str) -> Result<Vec<u8>, Error> {
+    BASE64
+        .decode(get_str(map, attribute)?)
+        .map_err(|_| Error::new(attribute, Cause::IsInvalid))
+}
+
 pub fn get_bool_optional(map: &Map<String, Value>, attribute: &'static str) -> Result<bool, Error> {
     map.get(attribute)
         .map(|x| {
@@ -289,6 +334,16 @@ pub fn get_bool_optional(map: &Map<String, Value>, attribute: &'static str) -> R
         .map(|x| x.unwrap_or(false))
 }
 
+pub fn get_bool_or_true(map: &Map<String, Value>, attribute: &'static str) -> Result<bool, Error> {
+    map.get(attribute)
+        .map(|x| {
+            x.as_bool()
+                .ok_or_else(|| Error::new(attribute, Cause::MustBeBoolean))
+        })
+        .transpose()
+        .map(|x| x.unwrap_or(true))
+}
+
 pub fn get_bool(map: &Map<String, Value>, attribute: &'static str) -> Result<bool, Error> {
     map.get(attribute)
         .ok_or_else(|| Error::new(attribute, Cause::IsMandatory))
@@ -322,6 +377,10 @@ pub fn get_i64_optional(
         .transpose()
 }
 
+pub fn get_i64(map: &Map<String, Value>, attribute: &'static str) -> Result<i64, Error> {
+    get_i64_optional(map, attribute)?.ok_or_else(|| Error::new(attribute, Cause::IsMandatory))
+}
+
 pub fn get_u64(map: &Map<String, Value>, attribute: &'static str) -> Result<u64, Error> {
     get_u64_optional(map, attribute)?.ok_or_else(|| Error::new(attribute, Cause::IsMandatory))
 }
@@ -333,6 +392,10 @@ pub fn get_u32_optional(
     get_u64_optional(map, attribute).map(|x| x.map(|n| n as u32))
 }
 
+pub fn get_u32(map: &Map<String, Value>, attribute: &'static str) -> Result<u32, Error> {
+    get_u32_optional(map, attribute)?.ok_or_else(|| Error::new(attribute, Cause::IsMandatory))
+}
+
 pub fn attribute<T>(attribute: &'static str, v: T) -> impl Iterator<Item = Option<(String, Value)>>
 where
     T: Into<Value>,
@@ -340,6 +403,12 @@ where
     iter::once(Some((attribute.to_string(), v.into())))
 }
 
+pub fn attribute_additional(
+    attributes: Map<String, Value>,
+) -> impl Iterator<Item = Option<(String, Value)>> {
+    attributes.into_iter().map(Some)
+}
+
 pub fn attribute_optional<T>(
     attribute: &'static str,
     v: Option<T>,
@@ -393,6 +462,18 @@ where
     iter::once(Some((attribute.to_string(), Value::String(s.into()))))
 }
 
+pub fn attribute_string_or_null<T>(
+    attribute: &'static str,
+    s: Option<T>,
+) -> impl Iterator<Item = Option<(String, Value)>>
+where
+    T: Into<String>,
+{
+    let s = s.map(|s| Value::String(s.into())).unwrap_or(Value::Null);
+
+    iter::once(Some((attribute.to_string(), s)))
+}
+
 pub fn attribute_u32_optional(
     attribute: &'static str,
     n: Option<u32>,
@@ -400,6 +481,13 @@ pub fn attribute_u32_optional(
     iter::once(n.map(|n| (attribute.to_string(), Value::Number(n.into()))))
 }
 
+pub fn attribute_u32(
+    attribute: &'static str,
+    n: u32,
+) -> impl Iterator<Item = Option<(String, Value)>> {
+    iter::once(Some((attribute.to_string(), Value::Number(n.into()))))
+}
+
 pub fn attribute_u64_optional(
     attribute: &'static str,
     n: Option<u64>,
@@ -414,6 +502,13 @@ pub fn attribute_u64(
     iter::once(Some((attribute.to_string(), Value::Number(n.into()))))
 }
 
+pub fn attribute_i64(
+    attribute: &'static str,
+    n: i64,
+) -> impl Iterator<Item = Option<(String, Value)>> {
+    iter::once(Some((attribute.to_string(), Value::Number(n.into()))))
+}
+
 pub fn attribute_i64_optional(
     attribute: &'static str,
     n: Option<i64>,
@@ -466,6 +561,25 @@ where
     iter::once(Some((attribute.to_string(), Value::Array(a))))
 }
 
+pub fn attribute_base64_optional(
+    attribute: &'static str,
+    bytes: Vec<u8>,
+) -> impl Iterator<Item = Option<(String, Value)>> {
+    iter::once(
+        (!bytes.is_empty()).then(|| (attribute.to_string(), Value::String(BASE64.encode(bytes)))),
+    )
+}
+
+pub fn attribute_base64(
+    attribute: &'static str,
+    bytes: Vec<u8>,
+) -> impl Iterator<Item = Option<(String, Value)>> {
+    iter::once(Some((
+        attribute.to_string(),
+        Value::String(BASE64.encode(bytes)),
+    )))
+}
+
 pub fn attribute_bool(
     attribute: &'static str,
     b: bool,